@@ -11,7 +11,11 @@ fn main() {
 #[cfg(not(any(target_os = "zkvm", doc)))]
 fn main() {
     use bls12_381::{G1Affine, G2Affine, Scalar};
-    use std::{fs, io::Write, path::Path};
+    use std::{
+        fs,
+        io::Write,
+        path::{Path, PathBuf},
+    };
 
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct KzgSettingsOwned {
@@ -22,8 +26,7 @@ fn main() {
 
     fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, KzgError> {
         let trimmed_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-        hex::decode(trimmed_str)
-            .map_err(|e| KzgError::InvalidHexFormat(format!("Failed to decode hex: {}", e)))
+        hex::decode(trimmed_str).map_err(KzgError::InvalidHexFormat)
     }
 
     pub fn load_trusted_setup_file_brute() -> Result<KzgSettingsOwned, KzgError> {
@@ -125,7 +128,12 @@ fn main() {
         let n2 = g2_points.len();
 
         if n1 < 2 || n2 < 2 {
-            return Err(KzgError::BadArgs("invalid args".to_string()));
+            return Err(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::PointCounts {
+                    num_g1_points: n1,
+                    num_g2_points: n2,
+                },
+            ));
         }
 
         let a1 = g1_points[1];
@@ -135,7 +143,9 @@ fn main() {
 
         let is_monomial_form = host_pairings_verify(a1, a2, b1, b2);
         if !is_monomial_form {
-            return Err(KzgError::BadArgs("not in monomial form".to_string()));
+            return Err(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::NotMonomialForm,
+            ));
         }
 
         Ok(())
@@ -143,10 +153,7 @@ fn main() {
 
     fn compute_roots_of_unity<const N: usize>(max_scale: usize) -> Result<[Scalar; N], KzgError> {
         if max_scale >= SCALE2_ROOT_OF_UNITY.len() {
-            return Err(KzgError::BadArgs(format!(
-                "The max scale should be lower than {}",
-                SCALE2_ROOT_OF_UNITY.len()
-            )));
+            return Err(KzgError::BadArgs(InputError::RootsOfUnityCount { n: N }));
         }
 
         let root_of_unity = Scalar::from_raw(SCALE2_ROOT_OF_UNITY[max_scale]);
@@ -158,9 +165,9 @@ fn main() {
 
     fn expand_root_of_unity(root: Scalar, width: usize) -> Result<Vec<Scalar>, KzgError> {
         if width < 2 {
-            return Err(KzgError::BadArgs(
-                "The width must be greater or equal to 2".to_string(),
-            ));
+            return Err(KzgError::BadArgs(InputError::RootsOfUnityCount {
+                n: width,
+            }));
         }
 
         let mut expanded = vec![Scalar::one(), root];
@@ -174,83 +181,199 @@ fn main() {
         }
 
         if expanded.last().unwrap() != &Scalar::one() {
-            return Err(KzgError::InvalidBytesLength(
-                "The last element value should be equal to 1".to_string(),
+            return Err(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::InvalidRootOfUnity,
             ));
         }
 
         Ok(expanded)
     }
 
+    /// Generates an insecure trusted setup of `N` Lagrange G1 points from a known `secret`, in the
+    /// same bit-reversed order as the setup loaded from `trusted_setup.txt`. A test checks that
+    /// it matches `InsecureTrustedSetup::generate`, which `build.rs` cannot include.
+    fn generate_insecure_setup<const N: usize>(
+        secret: Scalar,
+    ) -> Result<(Vec<Scalar>, Vec<G1Affine>, Vec<G2Affine>), KzgError> {
+        let roots_of_unity: [Scalar; N] = compute_roots_of_unity(N.trailing_zeros() as usize)?;
+        let vanishing = secret.pow_vartime(&[N as u64, 0, 0, 0]) - Scalar::one();
+        let width = Scalar::from(N as u64);
+
+        // The Lagrange basis polynomial of the root w is L(X) = w (X^N - 1) / (N (X - w)).
+        let g1_points = roots_of_unity
+            .iter()
+            .map(|&root| {
+                let denominator = (width * (secret - root)).invert().unwrap();
+                G1Affine::from(G1Affine::generator() * (root * vanishing * denominator))
+            })
+            .collect();
+
+        let mut power = Scalar::one();
+        let g2_points = (0..NUM_G2_POINTS)
+            .map(|_| {
+                let point = G2Affine::from(G2Affine::generator() * power);
+                power *= secret;
+                point
+            })
+            .collect();
+
+        Ok((roots_of_unity.to_vec(), g1_points, g2_points))
+    }
+
+    /// Writes the `.bin` files of a setup to `out_dir`, returning whether they all existed before.
+    fn write_setup(
+        out_dir: &Path,
+        roots_of_unity: &[Scalar],
+        g1_points: &[G1Affine],
+        g2_points: &[G2Affine],
+    ) -> bool {
+        let g1_path = Path::new(&out_dir).join("g1.bin");
+        let g2_path = Path::new(&out_dir).join("g2.bin");
+        let roots_of_unity_path = Path::new(&out_dir).join("roots_of_unity.bin");
+
+        let existed = g1_path.exists() && g2_path.exists() && roots_of_unity_path.exists();
+
+        let mut roots_of_unity_bytes: Vec<u8> = Vec::new();
+        let mut g1_bytes: Vec<u8> = Vec::new();
+        let mut g2_bytes: Vec<u8> = Vec::new();
+
+        roots_of_unity.iter().for_each(|&v| {
+            roots_of_unity_bytes
+                .extend_from_slice(unsafe { &std::mem::transmute::<Scalar, [u8; 32]>(v) });
+        });
+
+        g1_points.iter().for_each(|&v| {
+            g1_bytes.extend_from_slice(unsafe { &std::mem::transmute::<G1Affine, [u8; 104]>(v) });
+        });
+
+        g2_points.iter().for_each(|&v| {
+            g2_bytes.extend_from_slice(unsafe { &std::mem::transmute::<G2Affine, [u8; 200]>(v) });
+        });
+
+        fs::create_dir_all(out_dir).unwrap();
+
+        let mut roots_of_unity_file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&roots_of_unity_path)
+            .unwrap();
+
+        roots_of_unity_file
+            .write_all(&roots_of_unity_bytes)
+            .unwrap();
+
+        let mut g1_file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&g1_path)
+            .unwrap();
+
+        g1_file.write_all(&g1_bytes).unwrap();
+
+        let mut g2_file = fs::OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&g2_path)
+            .unwrap();
+
+        g2_file.write_all(&g2_bytes).unwrap();
+
+        existed
+    }
+
+    /// Collects the format, name and path of every `data.yaml` under `dir`. The format is the
+    /// parent directory of the case, or its grandparent in the `<format>/<suite>/<case>` layout
+    /// of the consensus-spec releases, where the suite is such as `kzg-mainnet`.
+    fn find_test_vectors(dir: &Path, vectors: &mut Vec<(String, String, PathBuf)>) {
+        // Every directory walked, so that adding a case or a suite anywhere reindexes.
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if !path.is_dir() || name.starts_with('.') || name == "target" {
+                continue;
+            }
+            let data = path.join("data.yaml");
+            if !data.is_file() {
+                find_test_vectors(&path, vectors);
+                continue;
+            }
+            let parent = dir.file_name().unwrap().to_string_lossy();
+            let format = if parent.starts_with("kzg-") {
+                dir.parent().unwrap().file_name().unwrap().to_string_lossy()
+            } else {
+                parent
+            };
+            vectors.push((format.into_owned(), name, data));
+        }
+    }
+
+    /// Classifies the `output` of a test vector as `true`, `false`, `null` or another value.
+    fn expected_output(data: &str) -> &'static str {
+        let output = data
+            .lines()
+            .find_map(|line| line.strip_prefix("output:"))
+            .map(str::trim);
+        match output {
+            Some("true") => "True",
+            Some("false") => "False",
+            Some("null") | Some("~") | None => "Null",
+            Some(_) => "Value",
+        }
+    }
+
+    /// Writes the index of the test vectors in `tests_dir` included by `src/test_vectors.rs`.
+    fn write_test_vector_index(tests_dir: &Path, index_path: &Path) {
+        let mut vectors = Vec::new();
+        find_test_vectors(tests_dir, &mut vectors);
+        vectors.sort();
+
+        let mut index = String::from("&[\n");
+        for (format, name, path) in &vectors {
+            let data = fs::read_to_string(path).unwrap();
+            index += &format!(
+                "    TestVector {{ format: {format:?}, name: {name:?}, output: ExpectedOutput::{}, data: include_str!({:?}) }},\n",
+                expected_output(&data),
+                path.display().to_string(),
+            );
+        }
+        index += "]\n";
+        fs::write(index_path, index).unwrap();
+    }
+
+    write_test_vector_index(
+        &get_repo_root().join("tests"),
+        &Path::new(&std::env::var("OUT_DIR").unwrap()).join("test_vectors.rs"),
+    );
+
     let mut out_dir = get_repo_root();
     out_dir.push("assets");
     out_dir.push("trusted_setup");
-    let g1_path = Path::new(&out_dir).join("g1.bin");
-    let g2_path = Path::new(&out_dir).join("g2.bin");
-    let roots_of_unity_path = Path::new(&out_dir).join("roots_of_unity.bin");
-    println!("g1_path: {:?}", g1_path);
-    println!("g2_path: {:?}", g2_path);
-    println!("roots_of_unity_path: {:?}", roots_of_unity_path);
-
-    let g1_exists = g1_path.exists();
-    let g2_exists = g2_path.exists();
-    let roots_of_unity_exists = roots_of_unity_path.exists();
-
-    if g1_exists && g2_exists && roots_of_unity_exists {
-        println!("cargo:rerun-if-changed=src/trusted_setup.rs"); // Re-run this build script if the `g1.bin`,`g2.bin`, or `roots_of_unity.bin` files are changed
-    }
 
     let KzgSettingsOwned {
         roots_of_unity,
         g1_points,
         g2_points,
     } = load_trusted_setup_file_brute().unwrap();
+    let mainnet_existed = write_setup(&out_dir, &roots_of_unity, &g1_points, &g2_points);
+
+    // The "minimal" preset uses an insecure setup with the same secret as the consensus-spec
+    // testing setups.
+    let (roots_of_unity, g1_points, g2_points) =
+        generate_insecure_setup::<NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL>(Scalar::from(1337u64))
+            .unwrap();
+    out_dir.push("minimal");
+    let minimal_existed = write_setup(&out_dir, &roots_of_unity, &g1_points, &g2_points);
 
-    let mut roots_of_unity_bytes: Vec<u8> = Vec::new();
-    let mut g1_bytes: Vec<u8> = Vec::new();
-    let mut g2_bytes: Vec<u8> = Vec::new();
-
-    roots_of_unity.iter().for_each(|&v| {
-        roots_of_unity_bytes
-            .extend_from_slice(unsafe { &std::mem::transmute::<Scalar, [u8; 32]>(v) });
-    });
-
-    g1_points.iter().for_each(|&v| {
-        g1_bytes.extend_from_slice(unsafe { &std::mem::transmute::<G1Affine, [u8; 104]>(v) });
-    });
-
-    g2_points.iter().for_each(|&v| {
-        g2_bytes.extend_from_slice(unsafe { &std::mem::transmute::<G2Affine, [u8; 200]>(v) });
-    });
-
-    let mut roots_of_unity_file = fs::OpenOptions::new()
-        .create(true)
-        .truncate(true)
-        .write(true)
-        .open(&roots_of_unity_path)
-        .unwrap();
-
-    roots_of_unity_file
-        .write_all(&roots_of_unity_bytes)
-        .unwrap();
-
-    let mut g1_file = fs::OpenOptions::new()
-        .create(true)
-        .truncate(true)
-        .write(true)
-        .open(&g1_path)
-        .unwrap();
-
-    g1_file.write_all(&g1_bytes).unwrap();
-
-    let mut g2_file = fs::OpenOptions::new()
-        .create(true)
-        .truncate(true)
-        .write(true)
-        .open(&g2_path)
-        .unwrap();
-
-    g2_file.write_all(&g2_bytes).unwrap();
+    if mainnet_existed && minimal_existed {
+        println!("cargo:rerun-if-changed=src/trusted_setup.rs"); // Re-run this build script if the `g1.bin`,`g2.bin`, or `roots_of_unity.bin` files are changed
+    }
 }
 
 pub fn get_repo_root() -> std::path::PathBuf {
@@ -0,0 +1,33 @@
+//! `Blob::as_polynomial` and the blob decoding of both backends accept the same blobs and decode
+//! them to the same evaluations.
+
+#![no_main]
+
+use bls12_381::Scalar;
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{fft::FftScalar, Blob, IntrinsicBackend, KzgBackend, Mainnet, Polynomial};
+
+type IntrinsicScalar = <IntrinsicBackend as KzgBackend>::Scalar;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(blob) = Blob::<Mainnet>::from_slice(data) else {
+        return;
+    };
+
+    let evaluations = blob.as_polynomial().ok();
+    assert_eq!(
+        Polynomial::<Scalar>::from_blob(&blob)
+            .ok()
+            .map(|polynomial| polynomial.evaluations().to_vec()),
+        evaluations
+    );
+    assert_eq!(
+        Polynomial::<IntrinsicScalar>::from_blob(&blob)
+            .ok()
+            .map(|polynomial| polynomial.evaluations().to_vec()),
+        evaluations.map(|evaluations| evaluations
+            .iter()
+            .map(IntrinsicScalar::from_scalar)
+            .collect())
+    );
+});
@@ -0,0 +1,39 @@
+//! The G1 decoders accept exactly the encodings `bls12_381` accepts.
+//!
+//! On the host `safe_g1_affine_from_bytes` is the native decoder, so the flag and canonicity
+//! checks of the guest decoder are fuzzed through `parse_compressed_g1`, with the decompression of
+//! `bls12_381`.
+
+#![no_main]
+
+use bls12_381::G1Affine;
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{
+    kzg_proof::{parse_compressed_g1, safe_g1_affine_from_bytes_native},
+    Bytes48,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(array) = <[u8; 48]>::try_from(data) else {
+        return;
+    };
+    let bytes = Bytes48::from_slice(data).unwrap();
+    let expected = Option::<G1Affine>::from(G1Affine::from_compressed(&array));
+    let expected_unchecked = Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&array));
+
+    let native = safe_g1_affine_from_bytes_native(&bytes);
+    assert_eq!(native.clone().ok(), expected);
+
+    let guest = parse_compressed_g1(&bytes).map(|parsed| {
+        let canonical = <[u8; 48]>::from(parsed.to_bytes());
+        Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&canonical))
+    });
+    match guest {
+        Ok(point) => assert_eq!(point, expected_unchecked),
+        // Both decoders reject a bad encoding with the same error.
+        Err(e) => {
+            assert_eq!(expected_unchecked, None);
+            assert_eq!(native, Err(e));
+        }
+    }
+});
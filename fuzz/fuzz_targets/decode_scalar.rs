@@ -0,0 +1,35 @@
+//! The scalar decoders of both backends accept exactly the canonical encodings, and re-encode
+//! them unchanged.
+
+#![no_main]
+
+use bls12_381::Scalar;
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{
+    backend::KzgScalar, fft::FftScalar, kzg_proof::safe_scalar_affine_from_bytes, Bytes32,
+    IntrinsicBackend, KzgBackend,
+};
+
+type IntrinsicScalar = <IntrinsicBackend as KzgBackend>::Scalar;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(bytes) = Bytes32::from_slice(data) else {
+        return;
+    };
+    let mut le_bytes = [0u8; 32];
+    le_bytes.copy_from_slice(data);
+    le_bytes.reverse();
+    let expected = Option::<Scalar>::from(Scalar::from_bytes(&le_bytes));
+
+    let native = safe_scalar_affine_from_bytes(&bytes).ok();
+    let intrinsic = IntrinsicScalar::from_be_bytes32(&bytes);
+    assert_eq!(native, expected);
+    assert_eq!(
+        intrinsic,
+        expected.as_ref().map(IntrinsicScalar::from_scalar)
+    );
+    if let (Some(native), Some(intrinsic)) = (native, intrinsic) {
+        assert_eq!(native.to_be_bytes32(), data);
+        assert_eq!(intrinsic.to_be_bytes32(), data);
+    }
+});
@@ -0,0 +1,22 @@
+//! Both backends give the same verdict on any blob, commitment and proof, laid out in that order,
+//! and never return an error.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{differential, get_kzg_verifier_settings, Blob, Bytes48, Mainnet, BYTES_PER_BLOB};
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() != BYTES_PER_BLOB + 96 {
+        return;
+    }
+    let (blob, points) = data.split_at(BYTES_PER_BLOB);
+    let blob = Blob::<Mainnet>::from_slice(blob).unwrap();
+    let commitment = Bytes48::from_slice(&points[..48]).unwrap();
+    let proof = Bytes48::from_slice(&points[48..]).unwrap();
+
+    let kzg_settings = get_kzg_verifier_settings();
+    differential::verify_blob_kzg_proof(&blob, &commitment, &proof, &kzg_settings)
+        .expect_agreement()
+        .unwrap();
+});
@@ -0,0 +1,29 @@
+//! Both backends give the same verdict on any batch, laid out as a blob, commitment and proof
+//! after another, and never return an error.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{differential, get_kzg_verifier_settings, Blob, Bytes48, Mainnet, BYTES_PER_BLOB};
+
+const CASE_LEN: usize = BYTES_PER_BLOB + 96;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() % CASE_LEN != 0 {
+        return;
+    }
+    let mut blobs = Vec::new();
+    let mut commitments = Vec::new();
+    let mut proofs = Vec::new();
+    for case in data.chunks(CASE_LEN) {
+        let (blob, points) = case.split_at(BYTES_PER_BLOB);
+        blobs.push(Blob::<Mainnet>::from_slice(blob).unwrap());
+        commitments.push(Bytes48::from_slice(&points[..48]).unwrap());
+        proofs.push(Bytes48::from_slice(&points[48..]).unwrap());
+    }
+
+    let kzg_settings = get_kzg_verifier_settings();
+    differential::verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings)
+        .expect_agreement()
+        .unwrap();
+});
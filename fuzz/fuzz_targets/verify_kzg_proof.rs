@@ -0,0 +1,22 @@
+//! Both backends give the same verdict on any commitment, `z`, `y` and proof, laid out in that
+//! order, and never return an error.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use openvm_kzg::{differential, get_kzg_point_verifier_settings, Bytes32, Bytes48};
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() != 160 {
+        return;
+    }
+    let commitment = Bytes48::from_slice(&data[..48]).unwrap();
+    let z = Bytes32::from_slice(&data[48..80]).unwrap();
+    let y = Bytes32::from_slice(&data[80..112]).unwrap();
+    let proof = Bytes48::from_slice(&data[112..]).unwrap();
+
+    let kzg_settings = get_kzg_point_verifier_settings();
+    differential::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings)
+        .expect_agreement()
+        .unwrap();
+});
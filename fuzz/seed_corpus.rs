@@ -0,0 +1,97 @@
+//! Seeds the corpus of every fuzz target with the inputs of the consensus-spec vectors in
+//! `tests/`, laid out as the target expects.
+//!
+//! Run `cargo run --bin seed_corpus` in `fuzz/` before fuzzing. Inputs that do not parse, such as
+//! points of the wrong length, are skipped.
+
+use std::{error::Error, fs, path::PathBuf};
+
+use openvm_kzg::test_vectors::{
+    test_vectors, VerifyBlobKzgProof, VerifyBlobKzgProofBatch, VerifyKzgProof,
+};
+
+type Result<T> = core::result::Result<T, Box<dyn Error>>;
+
+fn main() -> Result<()> {
+    let corpus = Corpus(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("corpus"));
+
+    for (case, test) in test_vectors::<VerifyKzgProof>() {
+        let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+            test.input.get_commitment(),
+            test.input.get_z(),
+            test.input.get_y(),
+            test.input.get_proof(),
+        ) else {
+            continue;
+        };
+        corpus.add(
+            "decode_g1",
+            &format!("{case}_commitment"),
+            &[commitment.as_slice()],
+        )?;
+        corpus.add("decode_g1", &format!("{case}_proof"), &[proof.as_slice()])?;
+        corpus.add("decode_scalar", &format!("{case}_z"), &[z.as_slice()])?;
+        corpus.add("decode_scalar", &format!("{case}_y"), &[y.as_slice()])?;
+        corpus.add(
+            "verify_kzg_proof",
+            case,
+            &[
+                commitment.as_slice(),
+                z.as_slice(),
+                y.as_slice(),
+                proof.as_slice(),
+            ],
+        )?;
+    }
+
+    for (case, test) in test_vectors::<VerifyBlobKzgProof>() {
+        let (Ok(blob), Ok(commitment), Ok(proof)) = (
+            test.input.get_blob(),
+            test.input.get_commitment(),
+            test.input.get_proof(),
+        ) else {
+            continue;
+        };
+        corpus.add("blob_to_polynomial", case, &[blob.as_slice()])?;
+        corpus.add(
+            "verify_blob_kzg_proof",
+            case,
+            &[blob.as_slice(), commitment.as_slice(), proof.as_slice()],
+        )?;
+    }
+
+    for (case, test) in test_vectors::<VerifyBlobKzgProofBatch>() {
+        let (Ok(blobs), Ok(commitments), Ok(proofs)) = (
+            test.input.get_blobs(),
+            test.input.get_commitments(),
+            test.input.get_proofs(),
+        ) else {
+            continue;
+        };
+        if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+            continue;
+        }
+        let parts: Vec<&[u8]> = blobs
+            .iter()
+            .zip(&commitments)
+            .zip(&proofs)
+            .flat_map(|((blob, commitment), proof)| {
+                [blob.as_slice(), commitment.as_slice(), proof.as_slice()]
+            })
+            .collect();
+        corpus.add("verify_blob_kzg_proof_batch", case, &parts)?;
+    }
+    Ok(())
+}
+
+struct Corpus(PathBuf);
+
+impl Corpus {
+    /// Writes the concatenation of `parts` as the input `name` of the target.
+    fn add(&self, target: &str, name: &str, parts: &[&[u8]]) -> Result<()> {
+        let dir = self.0.join(target);
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(name), parts.concat())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+//! The arithmetic that verification runs on.
+//!
+//! [`KzgBackend`] covers decoding, scalar multiplication and the pairing check. There are two
+//! backends, which can be used side by side in one build:
+//!
+//! - [`IntrinsicBackend`] uses the OpenVM types, which run on the OpenVM intrinsics in a guest and
+//!   on the host-mode field implementations elsewhere.
+//! - [`NativeBackend`] uses `bls12_381`.
+//!
+//! The `use-intrinsics` feature only chooses [`DefaultBackend`], which [`crate::KzgProof`] uses.
+
+use core::fmt::Debug;
+use core::ops::{Neg, Sub};
+
+use alloc::vec::Vec;
+use bls12_381::{
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt, Scalar,
+};
+use {
+    hex_literal::hex,
+    openvm_algebra_guest::{field::FieldExtension, DivUnsafe, IntMod},
+    openvm_ecc_guest::{
+        weierstrass::{CachedMulTable, IntrinsicCurve, WeierstrassPoint},
+        AffinePoint, CyclicGroup, Group,
+    },
+    openvm_pairing::bls12_381::{
+        Bls12_381 as Bls12_381_G1, Fp, Fp2, G1Affine as Bls12_381G1Affine,
+        G2Affine as Bls12_381G2Affine, Scalar as Bls12_381Scalar,
+    },
+};
+
+use crate::fft::FftScalar;
+use crate::kzg_proof::{
+    g1_affine_from_validated_bytes, g1_affine_from_validated_bytes_native,
+    safe_g1_affine_from_bytes, safe_g1_affine_from_bytes_native,
+};
+use crate::{Bytes32, Bytes48, KzgError};
+
+/// The backend [`crate::KzgProof`] verifies with: [`IntrinsicBackend`] with `use-intrinsics`,
+/// [`NativeBackend`] otherwise.
+#[cfg(feature = "use-intrinsics")]
+pub type DefaultBackend = IntrinsicBackend;
+
+/// The backend [`crate::KzgProof`] verifies with: [`IntrinsicBackend`] with `use-intrinsics`,
+/// [`NativeBackend`] otherwise.
+#[cfg(not(feature = "use-intrinsics"))]
+pub type DefaultBackend = NativeBackend;
+
+/// The scalar field element of the default backend.
+pub type BackendScalar = <DefaultBackend as KzgBackend>::Scalar;
+
+/// A scalar field element of a backend.
+pub trait KzgScalar: FftScalar + Neg<Output = Self> + PartialEq + Debug {
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn from_u64(value: u64) -> Self;
+
+    /// `self` must be non-zero.
+    fn invert(&self) -> Self;
+
+    /// Parses a big-endian scalar, returning `None` for values that are not reduced.
+    fn from_be_bytes32(bytes: &Bytes32) -> Option<Self>;
+
+    /// Returns the canonical big-endian encoding.
+    fn to_be_bytes32(&self) -> [u8; 32];
+}
+
+impl KzgScalar for Scalar {
+    fn zero() -> Self {
+        Scalar::zero()
+    }
+
+    fn one() -> Self {
+        Scalar::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Scalar::from(value)
+    }
+
+    fn invert(&self) -> Self {
+        Scalar::invert(self).unwrap()
+    }
+
+    fn from_be_bytes32(bytes: &Bytes32) -> Option<Self> {
+        crate::kzg_proof::safe_scalar_affine_from_bytes(bytes).ok()
+    }
+
+    fn to_be_bytes32(&self) -> [u8; 32] {
+        let mut bytes = self.to_bytes();
+        bytes.reverse();
+        bytes
+    }
+}
+
+impl KzgScalar for Bls12_381Scalar {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn from_u64(value: u64) -> Self {
+        <Self as IntMod>::from_u64(value)
+    }
+
+    fn invert(&self) -> Self {
+        Self::ONE.div_unsafe(self)
+    }
+
+    fn from_be_bytes32(bytes: &Bytes32) -> Option<Self> {
+        <Self as IntMod>::from_be_bytes(bytes.as_slice())
+    }
+
+    fn to_be_bytes32(&self) -> [u8; 32] {
+        // The guest representation may be unreduced.
+        self.assert_reduced();
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(<Self as IntMod>::to_be_bytes(self).as_ref());
+        bytes
+    }
+}
+
+/// Decoding, scalar multiplication and the pairing check over BLS12-381.
+pub trait KzgBackend {
+    type Scalar: KzgScalar;
+    type G1: Clone + Sub<Output = Self::G1>;
+    type G2: Clone + Sub<Output = Self::G2>;
+
+    /// Decodes a compressed G1 point, checking that it is in the subgroup.
+    fn decode_g1(bytes: &Bytes48) -> Result<Self::G1, KzgError>;
+
+    /// Decodes a compressed G1 point that was already decoded by [`Self::decode_g1`] of either
+    /// backend, such as that of a [`crate::KzgCommitment`], without checking the subgroup again.
+    ///
+    /// Panics if the point does not decompress.
+    fn decode_validated_g1(bytes: &Bytes48) -> Self::G1;
+
+    /// Converts a G2 point of a trusted setup.
+    fn g2_from_affine(point: &G2Affine) -> Self::G2;
+
+    fn g1_generator() -> Self::G1;
+
+    fn g2_generator() -> Self::G2;
+
+    /// Returns `[scalar] G1`.
+    fn g1_mul_generator(scalar: &Self::Scalar) -> Self::G1;
+
+    /// Returns `[scalar] G2`.
+    fn g2_mul_generator(scalar: &Self::Scalar) -> Self::G2;
+
+    /// Returns `sum scalars[i] points[i]`. The slices must have the same length.
+    fn g1_msm(scalars: &[Self::Scalar], points: &[Self::G1]) -> Self::G1;
+
+    /// Checks `e(p0, p1) == e(q0, q1)`.
+    fn pairings_verify(p0: Self::G1, p1: Self::G2, q0: Self::G1, q1: Self::G2) -> bool;
+}
+
+/// The OpenVM types, on the intrinsics in a guest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IntrinsicBackend;
+
+/// Pure Rust arithmetic with `bls12_381`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NativeBackend;
+
+const G2_AFFINE_GENERATOR: Bls12_381G2Affine = Bls12_381G2Affine::new(
+    Fp2::new(
+        Fp::from_const_bytes(hex!("B8BD21C1C85680D4EFBB05A82603AC0B77D1E37A640B51B4023B40FAD47AE4C65110C52D27050826910A8FF0B2A24A02")),
+        Fp::from_const_bytes(hex!("7E2B045D057DACE5575D941312F14C3349507FDCBB61DAB51AB62099D0D06B59654F2788A0D3AC7D609F7152602BE013"))
+    ),
+    Fp2::new(
+        Fp::from_const_bytes(hex!("0128B808865493E189A2AC3BCCC93A922CD16051699A426DA7D3BD8CAA9BFDAD1A352EDAC6CDC98C116E7D7227D5E50C")),
+        Fp::from_const_bytes(hex!("BE795FF05F07A9AAA11DEC5C270D373FAB992E57AB927426AF63A7857E283ECB998BC22BB0D2AC32CC34A72EA0C40606"))
+    )
+);
+
+// Used for CachedMulTable implementation of msm for Bls12_381_G2.
+#[allow(non_camel_case_types)]
+struct Bls12_381_G2;
+
+impl IntrinsicCurve for Bls12_381_G2 {
+    type Scalar = Bls12_381Scalar; // order of the generator is prime
+    type Point = Bls12_381G2Affine;
+
+    fn msm(coeffs: &[Self::Scalar], bases: &[Self::Point]) -> Self::Point {
+        openvm_ecc_guest::msm(coeffs, bases)
+    }
+}
+
+impl KzgBackend for IntrinsicBackend {
+    type Scalar = Bls12_381Scalar;
+    type G1 = Bls12_381G1Affine;
+    type G2 = Bls12_381G2Affine;
+
+    fn decode_g1(bytes: &Bytes48) -> Result<Self::G1, KzgError> {
+        safe_g1_affine_from_bytes(bytes)
+    }
+
+    fn decode_validated_g1(bytes: &Bytes48) -> Self::G1 {
+        g1_affine_from_validated_bytes(bytes)
+    }
+
+    fn g2_from_affine(point: &G2Affine) -> Self::G2 {
+        to_openvm_g2_affine(*point)
+    }
+
+    fn g1_generator() -> Self::G1 {
+        Bls12_381G1Affine::GENERATOR
+    }
+
+    fn g2_generator() -> Self::G2 {
+        G2_AFFINE_GENERATOR.clone()
+    }
+
+    fn g1_mul_generator(scalar: &Self::Scalar) -> Self::G1 {
+        // We use the fact that Bls12_381G1Affine::GENERATOR has prime order.
+        let table = CachedMulTable::<Bls12_381_G1>::new_with_prime_order(
+            &[Bls12_381G1Affine::GENERATOR],
+            4,
+        );
+        table.windowed_mul(core::slice::from_ref(scalar))
+    }
+
+    fn g2_mul_generator(scalar: &Self::Scalar) -> Self::G2 {
+        // We use the fact that g2_affine_generator has prime order.
+        let table = CachedMulTable::<Bls12_381_G2>::new_with_prime_order(&[G2_AFFINE_GENERATOR], 4);
+        table.windowed_mul(core::slice::from_ref(scalar))
+    }
+
+    fn g1_msm(scalars: &[Self::Scalar], points: &[Self::G1]) -> Self::G1 {
+        openvm_ecc_guest::msm(scalars, points)
+    }
+
+    fn pairings_verify(p0: Self::G1, p1: Self::G2, q0: Self::G1, q1: Self::G2) -> bool {
+        use openvm_pairing::{bls12_381::Bls12_381, PairingCheck};
+
+        let [p0, q0] = [p0, q0].map(|p| {
+            let (x, y) = p.into_coords();
+            AffinePoint::new(x, y)
+        });
+        let g1_points = [-p0, q0];
+        let g2_points = [p1, q1].map(Into::into);
+
+        Bls12_381::pairing_check(&g1_points, &g2_points).is_ok()
+    }
+}
+
+impl KzgBackend for NativeBackend {
+    type Scalar = Scalar;
+    type G1 = G1Projective;
+    type G2 = G2Projective;
+
+    fn decode_g1(bytes: &Bytes48) -> Result<Self::G1, KzgError> {
+        safe_g1_affine_from_bytes_native(bytes).map(G1Projective::from)
+    }
+
+    fn decode_validated_g1(bytes: &Bytes48) -> Self::G1 {
+        G1Projective::from(g1_affine_from_validated_bytes_native(bytes))
+    }
+
+    fn g2_from_affine(point: &G2Affine) -> Self::G2 {
+        G2Projective::from(point)
+    }
+
+    fn g1_generator() -> Self::G1 {
+        G1Projective::generator()
+    }
+
+    fn g2_generator() -> Self::G2 {
+        G2Projective::generator()
+    }
+
+    fn g1_mul_generator(scalar: &Self::Scalar) -> Self::G1 {
+        G1Affine::generator() * scalar
+    }
+
+    fn g2_mul_generator(scalar: &Self::Scalar) -> Self::G2 {
+        G2Affine::generator() * scalar
+    }
+
+    fn g1_msm(scalars: &[Self::Scalar], points: &[Self::G1]) -> Self::G1 {
+        scalars
+            .iter()
+            .zip(points)
+            .fold(G1Projective::identity(), |acc, (scalar, point)| {
+                acc + point * scalar
+            })
+    }
+
+    fn pairings_verify(p0: Self::G1, p1: Self::G2, q0: Self::G1, q1: Self::G2) -> bool {
+        multi_miller_loop(&[
+            (&-G1Affine::from(p0), &G2Prepared::from(G2Affine::from(p1))),
+            (&G1Affine::from(q0), &G2Prepared::from(G2Affine::from(q1))),
+        ])
+        .final_exponentiation()
+            == Gt::identity()
+    }
+}
+
+/// Returns `sum r^i (C_i - [y_i] + [z_i] proof_i)` and `sum r^i proof_i`, the two G1 points of
+/// the batch pairing check.
+pub(crate) fn batch_lincombs<K: KzgBackend>(
+    commitments: &[K::G1],
+    zs: &[K::Scalar],
+    ys: &[K::Scalar],
+    proofs: &[K::G1],
+    r: &K::Scalar,
+) -> (K::G1, K::G1) {
+    let n = commitments.len();
+    let mut r_powers = Vec::with_capacity(n);
+    let mut coeffs = Vec::with_capacity(2 * n + 1);
+    let mut bases = Vec::with_capacity(2 * n + 1);
+    let mut y_lincomb = K::Scalar::zero();
+    let mut r_power = K::Scalar::one();
+    for (((commitment, z), y), proof) in commitments.iter().zip(zs).zip(ys).zip(proofs) {
+        coeffs.push(r_power.clone());
+        bases.push(commitment.clone());
+        coeffs.push(z.clone() * r_power.clone());
+        bases.push(proof.clone());
+        y_lincomb = y_lincomb + y.clone() * r_power.clone();
+        r_powers.push(r_power.clone());
+        r_power = r_power * r.clone();
+    }
+    coeffs.push(-y_lincomb);
+    bases.push(K::g1_generator());
+
+    (K::g1_msm(&coeffs, &bases), K::g1_msm(&r_powers, proofs))
+}
+
+/// Assumes that G2Affine is a point on the curve in the correct subgroup.
+fn to_openvm_g2_affine(g2: G2Affine) -> Bls12_381G2Affine {
+    if g2.is_identity().unwrap_u8() != 0 {
+        return <Bls12_381G2Affine as Group>::IDENTITY;
+    }
+    let g2_bytes = g2.to_uncompressed();
+    let x_c1: [u8; 48] = g2_bytes[0..48].try_into().unwrap();
+    let x_c0: [u8; 48] = g2_bytes[48..96].try_into().unwrap();
+    let y_c1: [u8; 48] = g2_bytes[96..144].try_into().unwrap();
+    let y_c0: [u8; 48] = g2_bytes[144..192].try_into().unwrap();
+
+    let ox = Fp2::from_coeffs([
+        Fp::from_be_bytes_unchecked(&x_c0),
+        Fp::from_be_bytes_unchecked(&x_c1),
+    ]);
+    let oy = Fp2::from_coeffs([
+        Fp::from_be_bytes_unchecked(&y_c0),
+        Fp::from_be_bytes_unchecked(&y_c1),
+    ]);
+    Bls12_381G2Affine::from_xy_unchecked(ox, oy)
+}
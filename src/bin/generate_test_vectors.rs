@@ -0,0 +1,63 @@
+//! Writes new test vectors in the consensus-spec layout, `<out_dir>/<format>/<case>/data.yaml`,
+//! generated deterministically from a seed with the embedded trusted setup of a preset.
+//!
+//! ```sh
+//! cargo run --release --features test-utils --bin generate-test-vectors -- out [seed] [minimal|mainnet] [count]
+//! ```
+
+use std::{error::Error, path::Path, process::ExitCode};
+
+use openvm_kzg::{
+    generator::{TestCase, VectorGenerator},
+    KzgPreset, Mainnet, Minimal,
+};
+
+const USAGE: &str =
+    "Usage: generate-test-vectors <out_dir> [seed] [minimal|mainnet] [count of random cases]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [out_dir, rest @ ..] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+    match run(Path::new(out_dir), rest) {
+        Ok(count) => {
+            println!("OK: wrote {count} cases to {out_dir}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("FAILED: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(out_dir: &Path, args: &[String]) -> Result<usize, Box<dyn Error>> {
+    let seed = match args.first() {
+        Some(seed) => seed
+            .parse()
+            .map_err(|e| format!("Invalid seed {seed}: {e}"))?,
+        None => 0,
+    };
+    let count = match args.get(2) {
+        Some(count) => count
+            .parse()
+            .map_err(|e| format!("Invalid count {count}: {e}"))?,
+        None => 4,
+    };
+    let cases = match args.get(1).map(String::as_str) {
+        Some("minimal") => generate::<Minimal>(seed, count)?,
+        Some("mainnet") | None => generate::<Mainnet>(seed, count)?,
+        Some(preset) => return Err(format!("Unknown preset {preset}\n{USAGE}").into()),
+    };
+    for case in &cases {
+        case.write(out_dir)
+            .map_err(|e| format!("Failed to write {}: {e}", case.name))?;
+    }
+    Ok(cases.len())
+}
+
+fn generate<P: KzgPreset>(seed: u64, count: usize) -> Result<Vec<TestCase>, Box<dyn Error>> {
+    Ok(VectorGenerator::<P>::new(seed).generate(count)?)
+}
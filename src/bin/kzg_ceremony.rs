@@ -0,0 +1,124 @@
+//! Runs a private powers-of-tau ceremony in the transcript format of the Ethereum KZG ceremony.
+//!
+//! ```sh
+//! # Start a ceremony, or continue one sub-ceremony of an existing transcript with a smaller size
+//! kzg-ceremony new <num_g1_powers> <num_g2_powers> <out.json>
+//! kzg-ceremony truncate <in.json> <num_g1_powers> <num_g2_powers> <out.json>
+//! # Each participant checks the transcript they receive and adds a random contribution
+//! kzg-ceremony contribute <in.json> <out.json>
+//! # Check a contribution, or a chain of them, and write the result for `build.rs`
+//! kzg-ceremony verify <previous.json> <next.json>
+//! kzg-ceremony export <in.json> <trusted_setup.txt>
+//! ```
+//!
+//! `truncate` reads either a single transcript or, like `verify-ceremony`, the `transcript.json`
+//! of the Ethereum ceremony, from which it takes the smallest sub-ceremony that is large enough.
+
+use std::{error::Error, process::ExitCode};
+
+use openvm_kzg::ceremony::{verify_contribution, CeremonyTranscript, Transcript};
+
+type Result<T> = core::result::Result<T, Box<dyn Error>>;
+
+const USAGE: &str = "Usage:
+  kzg-ceremony new <num_g1_powers> <num_g2_powers> <out.json>
+  kzg-ceremony truncate <in.json> <num_g1_powers> <num_g2_powers> <out.json>
+  kzg-ceremony contribute <in.json> <out.json>
+  kzg-ceremony verify <previous.json> <next.json>
+  kzg-ceremony export <in.json> <trusted_setup.txt>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        ["new", num_g1_powers, num_g2_powers, out] => new(num_g1_powers, num_g2_powers, out),
+        ["truncate", input, num_g1_powers, num_g2_powers, out] => {
+            truncate(input, num_g1_powers, num_g2_powers, out)
+        }
+        ["contribute", input, out] => contribute(input, out),
+        ["verify", previous, next] => verify(previous, next),
+        ["export", input, out] => export(input, out),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("FAILED: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new(num_g1_powers: &str, num_g2_powers: &str, out: &str) -> Result<()> {
+    let transcript = Transcript::new(parse_count(num_g1_powers)?, parse_count(num_g2_powers)?);
+    write(out, &transcript.to_json())
+}
+
+fn truncate(input: &str, num_g1_powers: &str, num_g2_powers: &str, out: &str) -> Result<()> {
+    let (num_g1_powers, num_g2_powers) = (parse_count(num_g1_powers)?, parse_count(num_g2_powers)?);
+    let json = read(input)?;
+    let transcript = match CeremonyTranscript::from_json(&json) {
+        Ok(ceremony) => ceremony
+            .transcripts
+            .into_iter()
+            .filter(|transcript| {
+                transcript.num_g1_powers >= num_g1_powers
+                    && transcript.num_g2_powers >= num_g2_powers
+            })
+            .min_by_key(|transcript| transcript.num_g1_powers)
+            .ok_or_else(|| {
+                format!("No sub-ceremony has {num_g1_powers} G1 and {num_g2_powers} G2 powers")
+            })?,
+        Err(_) => Transcript::from_json(&json)?,
+    };
+    transcript.verify()?;
+    let transcript = transcript.truncated(num_g1_powers, num_g2_powers)?;
+    write(out, &transcript.to_json())
+}
+
+fn contribute(input: &str, out: &str) -> Result<()> {
+    let mut transcript = Transcript::from_json(&read(input)?)?;
+    println!("Verifying the transcript before contributing");
+    transcript.verify()?;
+    transcript.contribute(&mut rand_core::OsRng)?;
+    write(out, &transcript.to_json())?;
+    println!(
+        "Contribution {} written to {out}",
+        transcript.witness.pot_pubkeys.len() - 1
+    );
+    Ok(())
+}
+
+fn verify(previous: &str, next: &str) -> Result<()> {
+    let previous = Transcript::from_json(&read(previous)?)?;
+    let next = Transcript::from_json(&read(next)?)?;
+    verify_contribution(&previous, &next)?;
+    println!(
+        "OK: {} new contributions",
+        next.witness.pot_pubkeys.len() - previous.witness.pot_pubkeys.len()
+    );
+    Ok(())
+}
+
+fn export(input: &str, out: &str) -> Result<()> {
+    let transcript = Transcript::from_json(&read(input)?)?;
+    let powers = transcript.verify()?;
+    write(out, &powers.to_trusted_setup_txt()?)
+}
+
+fn parse_count(count: &str) -> Result<usize> {
+    Ok(count
+        .parse()
+        .map_err(|e| format!("Invalid number of powers {count}: {e}"))?)
+}
+
+fn read(path: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(path).map_err(|e| format!("Failed to read {path}: {e}"))?)
+}
+
+fn write(path: &str, contents: &str) -> Result<()> {
+    Ok(std::fs::write(path, contents).map_err(|e| format!("Failed to write {path}: {e}"))?)
+}
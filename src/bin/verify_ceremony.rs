@@ -0,0 +1,67 @@
+//! Verifies a local copy of the Ethereum KZG ceremony `transcript.json` and that it produced the
+//! trusted setup embedded in this crate, or the one in a given `trusted_setup.txt`.
+//!
+//! ```sh
+//! cargo run --release --features ceremony --bin verify-ceremony -- transcript.json [trusted_setup.txt]
+//! ```
+
+use std::{error::Error, process::ExitCode};
+
+use openvm_kzg::{
+    ceremony::{verify_powers_match_settings, CeremonyTranscript},
+    get_kzg_settings, load_trusted_setup_from_path, CeremonyError,
+};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [transcript_path, rest @ ..] = args.as_slice() else {
+        eprintln!("Usage: verify-ceremony <transcript.json> [trusted_setup.txt]");
+        return ExitCode::FAILURE;
+    };
+    match run(transcript_path, rest.first()) {
+        Ok(()) => {
+            println!("OK: the trusted setup is the output of the ceremony transcript");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("FAILED: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(transcript_path: &str, trusted_setup_path: Option<&String>) -> Result<(), Box<dyn Error>> {
+    let kzg_settings = match trusted_setup_path {
+        Some(path) => {
+            load_trusted_setup_from_path(path).map_err(|e| format!("Failed to load {path}: {e}"))?
+        }
+        None => get_kzg_settings(),
+    };
+
+    let json = std::fs::read_to_string(transcript_path)
+        .map_err(|e| format!("Failed to read {transcript_path}: {e}"))?;
+    let ceremony = CeremonyTranscript::from_json(&json)?;
+
+    let mut matched = false;
+    for transcript in &ceremony.transcripts {
+        println!(
+            "Verifying the sub-ceremony of {} G1 and {} G2 powers with {} contributions",
+            transcript.num_g1_powers,
+            transcript.num_g2_powers,
+            transcript.witness.pot_pubkeys.len().saturating_sub(1)
+        );
+        let powers = transcript.verify()?;
+        if powers.g1_powers.len() == kzg_settings.g1_points.len() {
+            println!("Comparing its Lagrange form with the trusted setup");
+            verify_powers_match_settings(&powers, &kzg_settings)?;
+            matched = true;
+        }
+    }
+    if !matched {
+        return Err(CeremonyError::MissingSubCeremony {
+            num_g1_powers: kzg_settings.g1_points.len(),
+        }
+        .into());
+    }
+    Ok(())
+}
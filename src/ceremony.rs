@@ -0,0 +1,790 @@
+//! Offline verification of the [Ethereum KZG ceremony] transcript.
+//!
+//! The embedded trusted setup is the output of the ceremony's 4096-point sub-ceremony, converted
+//! to Lagrange form and bit-reversed. [`verify_ceremony_transcript`] checks a local copy of
+//! `transcript.json` and that its output is exactly the setup compiled into this crate, so the
+//! setup does not have to be trusted.
+//!
+//! The BLS signatures binding contributions to participant identities are not checked: they do not
+//! affect the soundness of the powers of tau.
+//!
+//! The same transcript format also runs private ceremonies for setups of other sizes:
+//! [`Transcript::new`] starts one (or [`Transcript::truncated`] continues an existing one),
+//! [`Transcript::contribute`] adds a random contribution with a Schnorr proof of knowledge of its
+//! secret, [`verify_contribution`] checks each step of the chain and
+//! [`PowersOfTau::to_trusted_setup_txt`] writes the result for `build.rs`.
+//!
+//! [Ethereum KZG ceremony]: https://github.com/ethereum/kzg-ceremony-specs
+
+use alloc::{format, string::String, vec, vec::Vec};
+use bls12_381::{
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt,
+    MillerLoopResult, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    encode_trusted_setup, fft::bit_reverse_in_place, kzg_proof::hash_to_scalar, CeremonyError,
+    KzgError, KzgSettings, TrustedSetupError, BYTES_PER_G1_POINT, BYTES_PER_G2_POINT,
+    SCALE2_ROOT_OF_UNITY,
+};
+
+/// Domain separator of the random linear combinations used to batch the pairing checks.
+const CEREMONY_CHECK_DOMAIN: &str = "OPENVM_KZG_CEREMONY_V1";
+/// Domain separator of the challenge of the proofs of knowledge of contributions.
+const PROOF_OF_KNOWLEDGE_DOMAIN: &str = "OPENVM_KZG_CONTRIBUTION_POK_V1";
+/// The number of running products whose Miller loops are computed at once.
+const RUNNING_PRODUCTS_CHUNK_SIZE: usize = 256;
+
+/// The `transcript.json` published by the ceremony sequencer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CeremonyTranscript {
+    /// One transcript per sub-ceremony, of 4096, 8192, 16384 and 32768 G1 powers.
+    pub transcripts: Vec<Transcript>,
+}
+
+/// The transcript of one sub-ceremony.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transcript {
+    pub num_g1_powers: usize,
+    pub num_g2_powers: usize,
+    pub powers_of_tau: PowersOfTauHex,
+    pub witness: Witness,
+}
+
+/// The hex-encoded, compressed `[τ^i]₁` and `[τ^i]₂` of a transcript.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowersOfTauHex {
+    #[serde(rename = "G1Powers")]
+    pub g1_powers: Vec<String>,
+    #[serde(rename = "G2Powers")]
+    pub g2_powers: Vec<String>,
+}
+
+/// The history of a transcript: the product `[τ₁⋯τ_i]₁` after each contribution, and each
+/// contribution's `[τ_i]₂`. The first entries are the generators.
+///
+/// Contributions made with [`Transcript::contribute`] also record a proof of knowledge of `τ_i`.
+/// The Ethereum ceremony has none, so empty entries are allowed and skipped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Witness {
+    pub running_products: Vec<String>,
+    pub pot_pubkeys: Vec<String>,
+    #[serde(default)]
+    pub bls_signatures: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proofs_of_knowledge: Vec<String>,
+}
+
+/// Decoded powers of tau, `[τ^i]₁` and `[τ^i]₂` in monomial form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowersOfTau {
+    pub g1_powers: Vec<G1Affine>,
+    pub g2_powers: Vec<G2Affine>,
+}
+
+impl CeremonyTranscript {
+    pub fn from_json(json: &str) -> Result<Self, KzgError> {
+        serde_json::from_str(json).map_err(json_error)
+    }
+
+    /// Verifies every sub-ceremony, returning their powers of tau.
+    pub fn verify(&self) -> Result<Vec<PowersOfTau>, KzgError> {
+        self.transcripts.iter().map(Transcript::verify).collect()
+    }
+}
+
+impl Transcript {
+    /// Starts a transcript of `τ = 1`, to which contributions can be added.
+    pub fn new(num_g1_powers: usize, num_g2_powers: usize) -> Self {
+        Self {
+            num_g1_powers,
+            num_g2_powers,
+            powers_of_tau: PowersOfTauHex {
+                g1_powers: vec![encode_g1(&G1Affine::generator()); num_g1_powers],
+                g2_powers: vec![encode_g2(&G2Affine::generator()); num_g2_powers],
+            },
+            witness: Witness {
+                running_products: vec![encode_g1(&G1Affine::generator())],
+                pot_pubkeys: vec![encode_g2(&G2Affine::generator())],
+                bls_signatures: vec![String::new()],
+                proofs_of_knowledge: vec![String::new()],
+            },
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, KzgError> {
+        serde_json::from_str(json).map_err(json_error)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("A transcript always serializes")
+    }
+
+    /// Keeps the first `num_g1_powers` and `num_g2_powers` powers, for example to continue a
+    /// larger ceremony with a smaller setup. The witness stays valid.
+    pub fn truncated(&self, num_g1_powers: usize, num_g2_powers: usize) -> Result<Self, KzgError> {
+        if num_g1_powers > self.num_g1_powers || num_g2_powers > self.num_g2_powers {
+            return Err(CeremonyError::TranscriptTruncation {
+                num_g1_powers: self.num_g1_powers,
+                num_g2_powers: self.num_g2_powers,
+                to_num_g1_powers: num_g1_powers,
+                to_num_g2_powers: num_g2_powers,
+            }
+            .into());
+        }
+        let mut transcript = self.clone();
+        transcript.num_g1_powers = num_g1_powers;
+        transcript.num_g2_powers = num_g2_powers;
+        transcript.powers_of_tau.g1_powers.truncate(num_g1_powers);
+        transcript.powers_of_tau.g2_powers.truncate(num_g2_powers);
+        Ok(transcript)
+    }
+
+    /// Multiplies `τ` by a fresh random secret, and records the contribution in the witness with
+    /// a proof of knowledge of the secret. The secret is dropped on return.
+    ///
+    /// The current powers are not verified first; use [`verify_contribution`] to check the
+    /// transcript a contribution is made to.
+    pub fn contribute<R: RngCore + CryptoRng>(&mut self, rng: &mut R) -> Result<(), KzgError> {
+        let mut powers = PowersOfTau {
+            g1_powers: decode_points(&self.powers_of_tau.g1_powers, decode_g1)?,
+            g2_powers: decode_points(&self.powers_of_tau.g2_powers, decode_g2)?,
+        };
+        if powers.g1_powers.len() < 2 || powers.g2_powers.len() < 2 {
+            return Err(CeremonyError::TooFewPowers.into());
+        }
+        let previous = powers.g1_powers[1];
+
+        let secret = random_nonzero_scalar(rng);
+        powers.update(secret);
+        let pubkey = G2Affine::from(G2Affine::generator() * secret);
+        let proof = ProofOfKnowledge::prove(secret, &previous, &powers.g1_powers[1], &pubkey, rng);
+
+        // Align the optional records with the public keys before appending.
+        let num_entries = self.witness.pot_pubkeys.len();
+        self.witness
+            .bls_signatures
+            .resize(num_entries, String::new());
+        self.witness
+            .proofs_of_knowledge
+            .resize(num_entries, String::new());
+
+        self.powers_of_tau = PowersOfTauHex::from(&powers);
+        self.witness
+            .running_products
+            .push(encode_g1(&powers.g1_powers[1]));
+        self.witness.pot_pubkeys.push(encode_g2(&pubkey));
+        self.witness.bls_signatures.push(String::new());
+        self.witness.proofs_of_knowledge.push(proof.to_hex());
+        Ok(())
+    }
+
+    /// Verifies that the powers are consecutive powers of one `τ`, and that `τ` is the product
+    /// of the contributions recorded in the witness. Returns the decoded powers.
+    pub fn verify(&self) -> Result<PowersOfTau, KzgError> {
+        let powers = PowersOfTau {
+            g1_powers: decode_points(&self.powers_of_tau.g1_powers, decode_g1)?,
+            g2_powers: decode_points(&self.powers_of_tau.g2_powers, decode_g2)?,
+        };
+        if powers.g1_powers.len() != self.num_g1_powers
+            || powers.g2_powers.len() != self.num_g2_powers
+        {
+            return Err(CeremonyError::PowersCount {
+                expected_g1: self.num_g1_powers,
+                expected_g2: self.num_g2_powers,
+                actual_g1: powers.g1_powers.len(),
+                actual_g2: powers.g2_powers.len(),
+            }
+            .into());
+        }
+        powers.verify()?;
+
+        let running_products = decode_points(&self.witness.running_products, decode_g1)?;
+        let pot_pubkeys = decode_points(&self.witness.pot_pubkeys, decode_g2)?;
+        verify_running_products(&running_products, &pot_pubkeys)?;
+        self.verify_proofs_of_knowledge(&running_products, &pot_pubkeys)?;
+        if running_products.last() != Some(&powers.g1_powers[1]) {
+            return Err(CeremonyError::LastRunningProduct.into());
+        }
+
+        Ok(powers)
+    }
+
+    /// Checks every recorded proof of knowledge against its step of the running products.
+    fn verify_proofs_of_knowledge(
+        &self,
+        running_products: &[G1Affine],
+        pot_pubkeys: &[G2Affine],
+    ) -> Result<(), KzgError> {
+        let proofs = &self.witness.proofs_of_knowledge;
+        if proofs.len() > pot_pubkeys.len() {
+            return Err(CeremonyError::ExtraProofsOfKnowledge.into());
+        }
+        for (i, proof) in proofs.iter().enumerate().skip(1) {
+            if proof.is_empty() {
+                continue;
+            }
+            let proof = ProofOfKnowledge::from_hex(proof)?;
+            if !proof.verify(
+                &running_products[i - 1],
+                &running_products[i],
+                &pot_pubkeys[i],
+            ) {
+                return Err(CeremonyError::InvalidProofOfKnowledge { contribution: i }.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&PowersOfTau> for PowersOfTauHex {
+    fn from(powers: &PowersOfTau) -> Self {
+        Self {
+            g1_powers: powers.g1_powers.iter().map(encode_g1).collect(),
+            g2_powers: powers.g2_powers.iter().map(encode_g2).collect(),
+        }
+    }
+}
+
+/// A Schnorr proof of knowledge in G2 of the secret `x` of a contribution `[x]₂`, bound to the
+/// running products before and after the contribution so it cannot be replayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofOfKnowledge {
+    pub commitment: G2Affine,
+    pub response: Scalar,
+}
+
+impl ProofOfKnowledge {
+    const BYTES: usize = BYTES_PER_G2_POINT + 32;
+
+    fn prove<R: RngCore + CryptoRng>(
+        secret: Scalar,
+        previous: &G1Affine,
+        next: &G1Affine,
+        pubkey: &G2Affine,
+        rng: &mut R,
+    ) -> Self {
+        let nonce = random_nonzero_scalar(rng);
+        let commitment = G2Affine::from(G2Affine::generator() * nonce);
+        let challenge = Self::challenge(previous, next, pubkey, &commitment);
+        Self {
+            commitment,
+            response: nonce + challenge * secret,
+        }
+    }
+
+    /// Checks `[s]₂ = R + c · [x]₂`.
+    pub fn verify(&self, previous: &G1Affine, next: &G1Affine, pubkey: &G2Affine) -> bool {
+        let challenge = Self::challenge(previous, next, pubkey, &self.commitment);
+        G2Projective::generator() * self.response == self.commitment + pubkey * challenge
+    }
+
+    fn challenge(
+        previous: &G1Affine,
+        next: &G1Affine,
+        pubkey: &G2Affine,
+        commitment: &G2Affine,
+    ) -> Scalar {
+        let mut hasher = Sha256::new();
+        hasher.update(PROOF_OF_KNOWLEDGE_DOMAIN.as_bytes());
+        hasher.update(previous.to_compressed());
+        hasher.update(next.to_compressed());
+        hasher.update(pubkey.to_compressed());
+        hasher.update(commitment.to_compressed());
+        hash_to_scalar(hasher.finalize().into())
+    }
+
+    /// Encodes the proof as the compressed commitment followed by the big-endian response.
+    pub fn to_hex(&self) -> String {
+        let mut response = self.response.to_bytes();
+        response.reverse();
+        format!(
+            "0x{}{}",
+            hex::encode(self.commitment.to_compressed()),
+            hex::encode(response)
+        )
+    }
+
+    pub fn from_hex(proof: &str) -> Result<Self, KzgError> {
+        let bytes: [u8; Self::BYTES] = decode_hex(proof)?;
+        let (commitment, response) = bytes.split_at(BYTES_PER_G2_POINT);
+        let commitment = Option::from(G2Affine::from_compressed(
+            commitment.try_into().expect("split at the G2 point size"),
+        ));
+        let mut response: [u8; 32] = response.try_into().expect("32 bytes remain");
+        response.reverse();
+        let response = Option::from(Scalar::from_bytes(&response));
+        match (commitment, response) {
+            (Some(commitment), Some(response)) => Ok(Self {
+                commitment,
+                response,
+            }),
+            _ => Err(CeremonyError::InvalidProofOfKnowledgeEncoding.into()),
+        }
+    }
+}
+
+/// Verifies that `next` is `previous` with contributions appended, each with a valid proof of
+/// knowledge, and that `next` is a valid transcript. Returns the powers of `next`.
+///
+/// Each participant of a private ceremony checks the transcript they receive with this before
+/// contributing, and everyone checks the final transcript against the first.
+pub fn verify_contribution(
+    previous: &Transcript,
+    next: &Transcript,
+) -> Result<PowersOfTau, KzgError> {
+    let (old, new) = (&previous.witness, &next.witness);
+    if previous.num_g1_powers != next.num_g1_powers || previous.num_g2_powers != next.num_g2_powers
+    {
+        return Err(CeremonyError::TranscriptSizes.into());
+    }
+    if new.pot_pubkeys.len() <= old.pot_pubkeys.len()
+        || !new.pot_pubkeys.starts_with(&old.pot_pubkeys)
+        || !new.running_products.starts_with(&old.running_products)
+    {
+        return Err(CeremonyError::NotAnExtension.into());
+    }
+    let num_proofs = new.proofs_of_knowledge.len();
+    if num_proofs != new.pot_pubkeys.len()
+        || new.proofs_of_knowledge[old.pot_pubkeys.len()..]
+            .iter()
+            .any(String::is_empty)
+    {
+        return Err(CeremonyError::MissingProofOfKnowledge.into());
+    }
+    next.verify()
+}
+
+impl PowersOfTau {
+    /// Multiplies `τ` by `secret`, raising each point `[τ^i]` to `[(τ · secret)^i]`.
+    pub fn update(&mut self, secret: Scalar) {
+        let mut power = Scalar::one();
+        let g1: Vec<G1Projective> = self
+            .g1_powers
+            .iter()
+            .map(|point| {
+                let updated = point * power;
+                power *= secret;
+                updated
+            })
+            .collect();
+        G1Projective::batch_normalize(&g1, &mut self.g1_powers);
+
+        let mut power = Scalar::one();
+        let g2: Vec<G2Projective> = self
+            .g2_powers
+            .iter()
+            .map(|point| {
+                let updated = point * power;
+                power *= secret;
+                updated
+            })
+            .collect();
+        G2Projective::batch_normalize(&g2, &mut self.g2_powers);
+    }
+
+    /// Encodes the powers in the `trusted_setup.txt` format that `build.rs` embeds, with the
+    /// Lagrange G1 points followed by the G2 and monomial G1 points.
+    pub fn to_trusted_setup_txt(&self) -> Result<String, KzgError> {
+        Ok(encode_trusted_setup(
+            &self.to_lagrange_g1_points()?,
+            &self.g2_powers,
+            &self.g1_powers,
+        ))
+    }
+
+    /// Checks that both sequences start at the generators and are consecutive powers of the same
+    /// non-zero `τ`. The pairing checks are batched with a random linear combination derived from
+    /// the points.
+    pub fn verify(&self) -> Result<(), KzgError> {
+        let (g1, g2) = (&self.g1_powers, &self.g2_powers);
+        if g1.len() < 2 || g2.len() < 2 {
+            return Err(CeremonyError::TooFewPowers.into());
+        }
+        if g1[0] != G1Affine::generator() || g2[0] != G2Affine::generator() {
+            return Err(CeremonyError::NotAtGenerators.into());
+        }
+        if bool::from(g1[1].is_identity()) {
+            return Err(CeremonyError::ZeroTau.into());
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(CEREMONY_CHECK_DOMAIN.as_bytes());
+        g1.iter()
+            .for_each(|point| hasher.update(point.to_compressed()));
+        g2.iter()
+            .for_each(|point| hasher.update(point.to_compressed()));
+        let rho = hash_to_scalar(hasher.finalize().into());
+
+        // Σ ρ^i [τ^(i+1)]₁ = τ · Σ ρ^i [τ^i]₁
+        let (g1_lower, g1_upper) = shifted_linear_combinations(g1, rho, G1Projective::identity());
+        if !pairings_equal(&g1_upper, &G2Affine::generator(), &g1_lower, &g2[1]) {
+            return Err(CeremonyError::G1PowersNotConsecutive.into());
+        }
+        // The same for G2, using [τ]₁ from the now verified G1 powers.
+        let (g2_lower, g2_upper) = shifted_linear_combinations(g2, rho, G2Projective::identity());
+        if !pairings_equal(&G1Affine::generator(), &g2_upper, &g1[1], &g2_lower) {
+            return Err(CeremonyError::G2PowersNotConsecutive.into());
+        }
+        Ok(())
+    }
+
+    /// Converts the G1 powers to the Lagrange basis of the roots of unity, in the bit-reversed
+    /// order of the embedded setup.
+    ///
+    /// The Lagrange point of the root `w^i` is `(1/n) Σ_j w^(-ij) [τ^j]₁`, an inverse FFT of the
+    /// monomial points over G1.
+    pub fn to_lagrange_g1_points(&self) -> Result<Vec<G1Affine>, KzgError> {
+        let n = self.g1_powers.len();
+        if !n.is_power_of_two() || n.trailing_zeros() as usize >= SCALE2_ROOT_OF_UNITY.len() {
+            return Err(CeremonyError::LagrangeSize { n }.into());
+        }
+        let root = Scalar::from_raw(SCALE2_ROOT_OF_UNITY[n.trailing_zeros() as usize]);
+        let inverse_root = root.invert().unwrap();
+        let inverse_width = Scalar::from(n as u64).invert().unwrap();
+
+        let mut points: Vec<G1Projective> = self.g1_powers.iter().map(G1Projective::from).collect();
+        fft_g1(&mut points, inverse_root);
+        points.iter_mut().for_each(|point| *point *= inverse_width);
+
+        let mut lagrange_points = vec![G1Affine::identity(); n];
+        G1Projective::batch_normalize(&points, &mut lagrange_points);
+        bit_reverse_in_place(&mut lagrange_points);
+        Ok(lagrange_points)
+    }
+}
+
+/// Verifies a ceremony transcript and that the output of its sub-ceremony of the same size as
+/// `kzg_settings` is exactly that trusted setup, for example the one from
+/// [`crate::get_kzg_settings`].
+pub fn verify_ceremony_transcript(json: &str, kzg_settings: &KzgSettings) -> Result<(), KzgError> {
+    let ceremony = CeremonyTranscript::from_json(json)?;
+    let powers = ceremony.verify()?;
+    let powers = powers
+        .iter()
+        .find(|powers| powers.g1_powers.len() == kzg_settings.g1_points.len())
+        .ok_or(CeremonyError::MissingSubCeremony {
+            num_g1_powers: kzg_settings.g1_points.len(),
+        })?;
+    verify_powers_match_settings(powers, kzg_settings)
+}
+
+/// Checks that `kzg_settings` is the Lagrange form of `powers`.
+pub fn verify_powers_match_settings(
+    powers: &PowersOfTau,
+    kzg_settings: &KzgSettings,
+) -> Result<(), KzgError> {
+    if powers.g2_powers[..] != kzg_settings.g2_points[..] {
+        return Err(CeremonyError::G2PowersMismatch.into());
+    }
+    if powers.to_lagrange_g1_points()?[..] != kzg_settings.g1_points[..] {
+        return Err(CeremonyError::LagrangeMismatch.into());
+    }
+    Ok(())
+}
+
+/// Checks `e(P_i, [1]₂) = e(P_(i-1), [τ_i]₂)` for every contribution, where `P_i` are the running
+/// products and `[τ_i]₂` the contributions' public keys, batched with a random linear combination.
+fn verify_running_products(
+    running_products: &[G1Affine],
+    pot_pubkeys: &[G2Affine],
+) -> Result<(), KzgError> {
+    if running_products.len() != pot_pubkeys.len() || running_products.is_empty() {
+        return Err(CeremonyError::RunningProductCount.into());
+    }
+    if running_products[0] != G1Affine::generator() || pot_pubkeys[0] != G2Affine::generator() {
+        return Err(CeremonyError::RunningProductsNotAtGenerators.into());
+    }
+    if pot_pubkeys
+        .iter()
+        .any(|pubkey| bool::from(pubkey.is_identity()))
+    {
+        return Err(CeremonyError::ZeroSecret.into());
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(CEREMONY_CHECK_DOMAIN.as_bytes());
+    running_products
+        .iter()
+        .for_each(|point| hasher.update(point.to_compressed()));
+    pot_pubkeys
+        .iter()
+        .for_each(|point| hasher.update(point.to_compressed()));
+    let rho = hash_to_scalar(hasher.finalize().into());
+
+    // Π e(ρ^i P_(i-1), [τ_i]₂) · e(-Σ ρ^i P_i, [1]₂) = 1
+    let mut products_lincomb = G1Projective::identity();
+    let mut miller_loop = MillerLoopResult::default();
+    let mut power = Scalar::one();
+    let indices: Vec<usize> = (1..running_products.len()).collect();
+    for chunk in indices.chunks(RUNNING_PRODUCTS_CHUNK_SIZE) {
+        let mut terms = Vec::with_capacity(chunk.len());
+        for &i in chunk {
+            power *= rho;
+            products_lincomb += running_products[i] * power;
+            terms.push((
+                G1Affine::from(running_products[i - 1] * power),
+                G2Prepared::from(pot_pubkeys[i]),
+            ));
+        }
+        let terms: Vec<_> = terms.iter().map(|(p, q)| (p, q)).collect();
+        miller_loop += multi_miller_loop(&terms);
+    }
+    let products_lincomb = G1Affine::from(-products_lincomb);
+    miller_loop +=
+        multi_miller_loop(&[(&products_lincomb, &G2Prepared::from(G2Affine::generator()))]);
+    if miller_loop.final_exponentiation() != Gt::identity() {
+        return Err(CeremonyError::RunningProductsMismatch.into());
+    }
+    Ok(())
+}
+
+/// Returns `(Σ ρ^i x_i, Σ ρ^i x_(i+1))` for `i < n - 1`.
+fn shifted_linear_combinations<A, P>(points: &[A], rho: Scalar, identity: P) -> (A, A)
+where
+    A: Copy + From<P>,
+    P: Copy + core::ops::Add<P, Output = P> + core::ops::Mul<Scalar, Output = P> + From<A>,
+{
+    let mut lower = identity;
+    let mut upper = identity;
+    let mut power = Scalar::one();
+    for window in points.windows(2) {
+        lower = lower + P::from(window[0]) * power;
+        upper = upper + P::from(window[1]) * power;
+        power *= rho;
+    }
+    (A::from(lower), A::from(upper))
+}
+
+/// Computes `x_i ← Σ_j ω^(ij) x_j` in place for a root of unity `ω` of order `values.len()`.
+pub(crate) fn fft_g1(values: &mut [G1Projective], root: Scalar) {
+    let n = values.len();
+    bit_reverse_in_place(values);
+    let mut size = 2;
+    while size <= n {
+        let step = root.pow_vartime(&[(n / size) as u64, 0, 0, 0]);
+        for chunk in values.chunks_mut(size) {
+            let (lower, upper) = chunk.split_at_mut(size / 2);
+            let mut twiddle = Scalar::one();
+            for (x, y) in lower.iter_mut().zip(upper) {
+                let t = *y * twiddle;
+                *y = *x - t;
+                *x += t;
+                twiddle *= step;
+            }
+        }
+        size *= 2;
+    }
+}
+
+fn pairings_equal(a1: &G1Affine, a2: &G2Affine, b1: &G1Affine, b2: &G2Affine) -> bool {
+    multi_miller_loop(&[(a1, &G2Prepared::from(*a2)), (&-b1, &G2Prepared::from(*b2))])
+        .final_exponentiation()
+        == Gt::identity()
+}
+
+fn random_nonzero_scalar<R: RngCore + CryptoRng>(rng: &mut R) -> Scalar {
+    loop {
+        let mut wide = [0u8; 64];
+        rng.fill_bytes(&mut wide);
+        let scalar = Scalar::from_bytes_wide(&wide);
+        if scalar != Scalar::zero() {
+            return scalar;
+        }
+    }
+}
+
+fn encode_g1(point: &G1Affine) -> String {
+    format!("0x{}", hex::encode(point.to_compressed()))
+}
+
+fn encode_g2(point: &G2Affine) -> String {
+    format!("0x{}", hex::encode(point.to_compressed()))
+}
+
+fn decode_points<T>(
+    hex_points: &[String],
+    decode: fn(&str) -> Result<T, KzgError>,
+) -> Result<Vec<T>, KzgError> {
+    hex_points.iter().map(|point| decode(point)).collect()
+}
+
+fn decode_g1(point: &str) -> Result<G1Affine, KzgError> {
+    let bytes: [u8; BYTES_PER_G1_POINT] = decode_hex(point)?;
+    Option::from(G1Affine::from_compressed(&bytes)).ok_or(KzgError::InvalidTrustedSetup(
+        TrustedSetupError::InvalidG1Point,
+    ))
+}
+
+fn decode_g2(point: &str) -> Result<G2Affine, KzgError> {
+    let bytes: [u8; BYTES_PER_G2_POINT] = decode_hex(point)?;
+    Option::from(G2Affine::from_compressed(&bytes)).ok_or(KzgError::InvalidTrustedSetup(
+        TrustedSetupError::InvalidG2Point,
+    ))
+}
+
+fn json_error(e: serde_json::Error) -> KzgError {
+    CeremonyError::InvalidJson {
+        line: e.line(),
+        column: e.column(),
+    }
+    .into()
+}
+
+fn decode_hex<const N: usize>(point: &str) -> Result<[u8; N], KzgError> {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(point.strip_prefix("0x").unwrap_or(point), &mut bytes)
+        .map_err(KzgError::InvalidHexFormat)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_minimal_kzg_settings;
+
+    /// Builds the transcript of a sub-ceremony of `num_g1_powers` with one contribution per secret.
+    fn transcript(secrets: &[u64], num_g1_powers: usize, num_g2_powers: usize) -> String {
+        let tau: Scalar = secrets.iter().map(|&s| Scalar::from(s)).product();
+        let powers = |count: usize| {
+            core::iter::successors(Some(Scalar::one()), move |power| Some(power * tau))
+                .take(count)
+                .collect::<Vec<_>>()
+        };
+        let g1_hex = |s: &Scalar| {
+            format!(
+                "0x{}",
+                hex::encode(G1Affine::from(G1Affine::generator() * s).to_compressed())
+            )
+        };
+        let g2_hex = |s: &Scalar| {
+            format!(
+                "0x{}",
+                hex::encode(G2Affine::from(G2Affine::generator() * s).to_compressed())
+            )
+        };
+
+        let mut products = vec![Scalar::one()];
+        for &s in secrets {
+            products.push(products.last().unwrap() * Scalar::from(s));
+        }
+        let pubkeys: Vec<Scalar> = core::iter::once(Scalar::one())
+            .chain(secrets.iter().map(|&s| Scalar::from(s)))
+            .collect();
+
+        serde_json::json!({
+            "transcripts": [{
+                "numG1Powers": num_g1_powers,
+                "numG2Powers": num_g2_powers,
+                "powersOfTau": {
+                    "G1Powers": powers(num_g1_powers).iter().map(g1_hex).collect::<Vec<_>>(),
+                    "G2Powers": powers(num_g2_powers).iter().map(g2_hex).collect::<Vec<_>>(),
+                },
+                "witness": {
+                    "runningProducts": products.iter().map(g1_hex).collect::<Vec<_>>(),
+                    "potPubkeys": pubkeys.iter().map(g2_hex).collect::<Vec<_>>(),
+                    "blsSignatures": vec![""; pubkeys.len()],
+                },
+            }],
+            "participantIds": [],
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_verify_ceremony_transcript_minimal() {
+        // The minimal setup's secret 1337 is the product of two contributions.
+        let json = transcript(&[7, 191], 4, 65);
+        verify_ceremony_transcript(&json, &get_minimal_kzg_settings()).unwrap();
+
+        // A different final secret verifies on its own but does not match the setup.
+        let json = transcript(&[7, 192], 4, 65);
+        CeremonyTranscript::from_json(&json)
+            .unwrap()
+            .verify()
+            .unwrap();
+        assert!(verify_ceremony_transcript(&json, &get_minimal_kzg_settings()).is_err());
+    }
+
+    #[test]
+    fn test_private_ceremony() {
+        let mut rng = rand_core::OsRng;
+        let start = Transcript::new(8, 4);
+        start.verify().unwrap();
+
+        let mut first = start.clone();
+        first.contribute(&mut rng).unwrap();
+        verify_contribution(&start, &first).unwrap();
+        let mut second = Transcript::from_json(&first.to_json()).unwrap();
+        second.contribute(&mut rng).unwrap();
+        verify_contribution(&first, &second).unwrap();
+        let powers = verify_contribution(&start, &second).unwrap();
+
+        // The setup written for `build.rs` loads back to the same points.
+        let txt = powers.to_trusted_setup_txt().unwrap();
+        let settings = crate::load_trusted_setup_from_str(&txt).unwrap();
+        assert_eq!(
+            settings.g1_points[..],
+            powers.to_lagrange_g1_points().unwrap()
+        );
+        assert_eq!(settings.g2_points[..], powers.g2_powers);
+
+        let mut unproven = second.clone();
+        unproven.witness.proofs_of_knowledge[2].clear();
+        unproven.verify().unwrap();
+        assert!(verify_contribution(&first, &unproven).is_err());
+
+        // A proof of knowledge is bound to its step of the chain.
+        let mut replayed = second.clone();
+        replayed.witness.proofs_of_knowledge[2] = second.witness.proofs_of_knowledge[1].clone();
+        assert!(replayed.verify().is_err());
+
+        assert!(verify_contribution(&second, &first).is_err());
+    }
+
+    #[test]
+    fn test_contribute_to_ceremony_transcript() {
+        // Continue a transcript without proofs of knowledge, as the Ethereum ceremony's, with a
+        // smaller setup.
+        let json = transcript(&[7, 191], 8, 4);
+        let ceremony = CeremonyTranscript::from_json(&json).unwrap();
+        let previous = ceremony.transcripts[0].truncated(4, 3).unwrap();
+        previous.verify().unwrap();
+
+        let mut next = previous.clone();
+        next.contribute(&mut rand_core::OsRng).unwrap();
+        let powers = verify_contribution(&previous, &next).unwrap();
+        assert_eq!(powers.g1_powers.len(), 4);
+        assert_eq!(powers.g2_powers.len(), 3);
+    }
+
+    #[test]
+    fn test_verify_ceremony_transcript_rejects_tampering() {
+        let json = transcript(&[7, 191], 8, 4);
+        let ceremony = CeremonyTranscript::from_json(&json).unwrap();
+        ceremony.verify().unwrap();
+
+        let mut tampered = ceremony.clone();
+        tampered.transcripts[0].powers_of_tau.g1_powers.swap(2, 3);
+        assert!(tampered.verify().is_err());
+
+        let mut tampered = ceremony.clone();
+        tampered.transcripts[0].powers_of_tau.g2_powers.swap(2, 3);
+        assert!(tampered.verify().is_err());
+
+        let mut tampered = ceremony.clone();
+        tampered.transcripts[0].witness.pot_pubkeys.swap(1, 2);
+        assert!(tampered.verify().is_err());
+
+        let mut tampered = ceremony;
+        tampered.transcripts[0].witness.running_products.pop();
+        tampered.transcripts[0].witness.pot_pubkeys.pop();
+        assert!(tampered.verify().is_err());
+    }
+}
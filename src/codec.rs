@@ -0,0 +1,150 @@
+//! Hex encoding of the byte types, and their serde representation: `0x`-prefixed hex in
+//! human-readable formats such as JSON, and raw bytes in binary formats such as the OpenVM input
+//! stream.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+use crate::KzgError;
+
+/// Parsing from hex, with or without a `0x` prefix.
+pub trait FromHex: Sized {
+    fn from_hex(hex: &str) -> Result<Self, KzgError>;
+}
+
+/// Decodes hex, with or without a `0x` prefix.
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, KzgError> {
+    hex::decode(hex.strip_prefix("0x").unwrap_or(hex)).map_err(KzgError::InvalidHexFormat)
+}
+
+/// Encodes bytes as `0x`-prefixed lowercase hex.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Formats bytes as lowercase hex, prefixed with `0x` with the `#` flag, as for integers.
+pub(crate) fn fmt_lower_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
+
+/// Serializes `bytes` as hex if the format is human-readable, and with `raw` otherwise.
+pub(crate) fn serialize_hex_or<S, F>(bytes: &[u8], serializer: S, raw: F) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    F: FnOnce(S) -> Result<S::Ok, S::Error>,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode_hex(bytes))
+    } else {
+        raw(serializer)
+    }
+}
+
+/// Deserializes a `T` from hex if the format is human-readable, and with `raw` otherwise.
+pub(crate) fn deserialize_hex_or<'de, D, T, F>(deserializer: D, raw: F) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromHex,
+    F: FnOnce(D) -> Result<T, D::Error>,
+{
+    if deserializer.is_human_readable() {
+        let hex = String::deserialize(deserializer)?;
+        T::from_hex(&hex).map_err(D::Error::custom)
+    } else {
+        raw(deserializer)
+    }
+}
+
+/// Implements [`FromHex`], `FromStr`, `Display` (`0x`-prefixed) and `LowerHex` for a type with
+/// `from_slice` and `as_slice`.
+macro_rules! impl_hex {
+    ($name:ident) => {
+        impl $crate::codec::FromHex for $name {
+            fn from_hex(hex: &str) -> Result<Self, $crate::KzgError> {
+                Self::from_slice(&$crate::codec::decode_hex(hex)?)
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = $crate::KzgError;
+
+            fn from_str(s: &str) -> Result<Self, $crate::KzgError> {
+                <Self as $crate::codec::FromHex>::from_hex(s)
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:#x}", self)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::codec::fmt_lower_hex(self.as_slice(), f)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_hex;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Bytes32, Bytes48, KzgInputs};
+
+    #[test]
+    fn test_hex_formatting() {
+        let hex = "0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+        let bytes: Bytes32 = hex.parse().unwrap();
+        assert_eq!(bytes.to_string(), hex);
+        assert_eq!(format!("{bytes:#x}"), hex);
+        assert_eq!(format!("{bytes:x}"), &hex[2..]);
+        assert_eq!(hex[2..].parse::<Bytes32>().unwrap(), bytes);
+        assert_eq!(Bytes32::from_hex(hex).unwrap(), bytes);
+
+        assert!(matches!(
+            "0xzz".parse::<Bytes32>(),
+            Err(KzgError::InvalidHexFormat(_))
+        ));
+        assert!(matches!(
+            "0x00".parse::<Bytes48>(),
+            Err(KzgError::InvalidBytesLength {
+                expected: 48,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_serde_human_readable() {
+        let bytes = Bytes48::from_slice(&[0xab; 48]).unwrap();
+        let yaml = serde_yaml::to_string(&bytes).unwrap();
+        assert_eq!(
+            serde_yaml::from_str::<String>(&yaml).unwrap(),
+            encode_hex(&[0xab; 48])
+        );
+        assert_eq!(serde_yaml::from_str::<Bytes48>(&yaml).unwrap(), bytes);
+
+        // Errors are reported instead of panicking.
+        assert!(serde_yaml::from_str::<Bytes48>("0xabab").is_err());
+        assert!(serde_yaml::from_str::<Bytes32>("not hex").is_err());
+
+        let inputs = KzgInputs {
+            commitment_bytes: bytes.clone(),
+            z_bytes: Bytes32::from_slice(&[1; 32]).unwrap(),
+            y_bytes: Bytes32::from_slice(&[2; 32]).unwrap(),
+            proof_bytes: bytes,
+        };
+        let yaml = serde_yaml::to_string(&inputs).unwrap();
+        let decoded: KzgInputs = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(decoded.z_bytes, inputs.z_bytes);
+        assert_eq!(decoded.proof_bytes, inputs.proof_bytes);
+    }
+}
@@ -0,0 +1,318 @@
+//! Runs the consensus-spec test vectors of every format against the crate.
+//!
+//! The verifiers are checked against [`KzgProof`], `compute_challenge` against
+//! [`compute_challenge`], and the formats that compute commitments and proofs against the
+//! reference prover of [`test_utils`](crate::test_utils). The EIP-7594 cell formats are parsed
+//! but not run, as the crate does not implement them.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    kzg_proof::compute_challenge,
+    test_utils::{blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, Test},
+    test_vectors::{
+        BlobToKzgCommitment, ComputeBlobKzgProof, ComputeCells, ComputeCellsAndKzgProofs,
+        ComputeChallenge, ComputeKzgProof, RecoverCellsAndKzgProofs, TestFormat, TestVector,
+        VerifyBlobKzgProof, VerifyBlobKzgProofBatch, VerifyCellKzgProofBatch, VerifyKzgProof,
+        TEST_VECTORS,
+    },
+    Bytes32, KzgProof, KzgSettings, VerificationOutcome,
+};
+
+/// A test vector that does not parse, or whose output differs from that of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub format: &'static str,
+    pub name: &'static str,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConformanceReport {
+    pub passed: usize,
+    pub failures: Vec<Failure>,
+    /// The number of vectors of formats that the crate does not implement.
+    pub unsupported: usize,
+}
+
+impl ConformanceReport {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unsupported",
+            self.passed,
+            self.failures.len(),
+            self.unsupported
+        )?;
+        for failure in &self.failures {
+            write!(
+                f,
+                "\n{}/{}: {}",
+                failure.format, failure.name, failure.reason
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs every test vector in `tests/` with `kzg_settings`.
+pub fn run_conformance(kzg_settings: &KzgSettings) -> ConformanceReport {
+    run_vectors(TEST_VECTORS, kzg_settings)
+}
+
+/// Runs the given test vectors with `kzg_settings`.
+pub fn run_vectors<'a>(
+    vectors: impl IntoIterator<Item = &'a TestVector>,
+    kzg_settings: &KzgSettings,
+) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+    for vector in vectors {
+        let result = match vector.format {
+            BlobToKzgCommitment::FORMAT => check::<BlobToKzgCommitment>(vector, |input| {
+                blob_to_kzg_commitment(&input.get_blob().ok()?, kzg_settings).ok()
+            }),
+            ComputeKzgProof::FORMAT => check::<ComputeKzgProof>(vector, |input| {
+                let (blob, z) = (input.get_blob().ok()?, input.get_z().ok()?);
+                compute_kzg_proof(&blob, &z, kzg_settings).ok()
+            }),
+            ComputeBlobKzgProof::FORMAT => check::<ComputeBlobKzgProof>(vector, |input| {
+                let (blob, commitment) = (input.get_blob().ok()?, input.get_commitment().ok()?);
+                compute_blob_kzg_proof(&blob, &commitment, kzg_settings).ok()
+            }),
+            ComputeChallenge::FORMAT => check::<ComputeChallenge>(vector, |input| {
+                let (blob, commitment) = (input.get_blob().ok()?, input.get_commitment().ok()?);
+                let mut challenge = compute_challenge(&blob, &commitment).to_bytes();
+                challenge.reverse();
+                Some(Bytes32(challenge))
+            }),
+            VerifyKzgProof::FORMAT => check::<VerifyKzgProof>(vector, |input| {
+                VerificationOutcome::spec_output(&KzgProof::verify_kzg_proof(
+                    &input.get_commitment().ok()?,
+                    &input.get_z().ok()?,
+                    &input.get_y().ok()?,
+                    &input.get_proof().ok()?,
+                    kzg_settings,
+                ))
+            }),
+            VerifyBlobKzgProof::FORMAT => check::<VerifyBlobKzgProof>(vector, |input| {
+                VerificationOutcome::spec_output(&KzgProof::verify_blob_kzg_proof(
+                    &input.get_blob().ok()?,
+                    &input.get_commitment().ok()?,
+                    &input.get_proof().ok()?,
+                    kzg_settings,
+                ))
+            }),
+            VerifyBlobKzgProofBatch::FORMAT => check::<VerifyBlobKzgProofBatch>(vector, |input| {
+                VerificationOutcome::spec_output(&KzgProof::verify_blob_kzg_proof_batch(
+                    &input.get_blobs().ok()?,
+                    &input.get_commitments().ok()?,
+                    &input.get_proofs().ok()?,
+                    kzg_settings,
+                ))
+            }),
+            ComputeCells::FORMAT => parse::<ComputeCells>(vector),
+            ComputeCellsAndKzgProofs::FORMAT => parse::<ComputeCellsAndKzgProofs>(vector),
+            VerifyCellKzgProofBatch::FORMAT => parse::<VerifyCellKzgProofBatch>(vector),
+            RecoverCellsAndKzgProofs::FORMAT => parse::<RecoverCellsAndKzgProofs>(vector),
+            format => Err(format!("unknown format {format}")),
+        };
+        match result {
+            Ok(Checked::Passed) => report.passed += 1,
+            Ok(Checked::Unsupported) => report.unsupported += 1,
+            Err(reason) => report.failures.push(Failure {
+                format: vector.format,
+                name: vector.name,
+                reason,
+            }),
+        }
+    }
+    report
+}
+
+enum Checked {
+    Passed,
+    Unsupported,
+}
+
+/// Compares the output of `run`, `None` if it rejects the input, with that of the vector.
+fn check<F>(
+    vector: &TestVector,
+    run: impl FnOnce(&F::Input) -> Option<F::Output>,
+) -> Result<Checked, String>
+where
+    F: TestFormat,
+    F::Output: PartialEq + fmt::Debug,
+{
+    let test: Test<F::Input, F::Output> =
+        vector.parse().map_err(|e| format!("does not parse: {e}"))?;
+    let output = run(&test.input);
+    if output == test.output {
+        Ok(Checked::Passed)
+    } else {
+        Err(format!("expected {:?}, got {output:?}", test.output))
+    }
+}
+
+fn parse<F: TestFormat>(vector: &TestVector) -> Result<Checked, String> {
+    vector
+        .parse::<F::Input, F::Output>()
+        .map(|_| Checked::Unsupported)
+        .map_err(|e| format!("does not parse: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        encode_hex, test_utils::BYTES_PER_CELL, test_vectors::ExpectedOutput, Blob, Bytes48,
+        FromHex, BYTES_PER_BLOB,
+    };
+    use alloc::string::ToString;
+    use bls12_381::{G1Affine, Scalar};
+
+    fn vector(format: &'static str, data: String) -> TestVector {
+        TestVector {
+            format,
+            name: "synthetic",
+            output: ExpectedOutput::Value,
+            data: data.leak(),
+        }
+    }
+
+    /// A blob whose field elements are all `value`.
+    fn constant_blob(value: u8) -> String {
+        let mut element = [0u8; 32];
+        element[31] = value;
+        encode_hex(&element.repeat(BYTES_PER_BLOB / 32))
+    }
+
+    fn g1(scalar: u64) -> String {
+        encode_hex(&G1Affine::from(G1Affine::generator() * Scalar::from(scalar)).to_compressed())
+    }
+
+    fn scalar(value: u8) -> String {
+        let mut bytes = [0u8; 32];
+        bytes[31] = value;
+        encode_hex(&bytes)
+    }
+
+    #[test]
+    fn test_conformance() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let report = run_conformance(&kzg_settings);
+        assert!(report.is_success(), "{report}");
+        assert_eq!(report.passed, TEST_VECTORS.len());
+    }
+
+    #[test]
+    fn test_compute_formats() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        // A constant polynomial commits to a multiple of the generator and has identity proofs.
+        let twos = constant_blob(2);
+        let identity = g1(0);
+        let mut non_canonical = [0u8; BYTES_PER_BLOB];
+        non_canonical[..32].copy_from_slice(&[0xff; 32]);
+        let non_canonical = encode_hex(&non_canonical);
+
+        let vectors = [
+            vector(
+                BlobToKzgCommitment::FORMAT,
+                format!("input: {{blob: '{twos}'}}\noutput: '{}'\n", g1(2)),
+            ),
+            vector(
+                BlobToKzgCommitment::FORMAT,
+                format!("input: {{blob: '{non_canonical}'}}\noutput: null\n"),
+            ),
+            vector(
+                ComputeKzgProof::FORMAT,
+                format!(
+                    "input: {{blob: '{twos}', z: '{}'}}\noutput: ['{identity}', '{}']\n",
+                    scalar(5),
+                    scalar(2)
+                ),
+            ),
+            vector(
+                ComputeKzgProof::FORMAT,
+                format!(
+                    "input: {{blob: '{twos}', z: '{}'}}\noutput: null\n",
+                    encode_hex(&[0xff; 32])
+                ),
+            ),
+            vector(
+                ComputeBlobKzgProof::FORMAT,
+                format!(
+                    "input: {{blob: '{twos}', commitment: '{}'}}\noutput: '{identity}'\n",
+                    g1(2)
+                ),
+            ),
+            vector(
+                ComputeBlobKzgProof::FORMAT,
+                format!("input: {{blob: '{twos}', commitment: '0x00'}}\noutput: null\n"),
+            ),
+            vector(
+                ComputeCells::FORMAT,
+                format!(
+                    "input: {{blob: '{twos}'}}\noutput: ['{}']\n",
+                    encode_hex(&[0; BYTES_PER_CELL])
+                ),
+            ),
+        ];
+        let report = run_vectors(&vectors, &kzg_settings);
+        assert!(report.is_success(), "{report}");
+        assert_eq!((report.passed, report.unsupported), (6, 1));
+
+        // A wrong output and a malformed vector are reported.
+        let vectors = [
+            vector(
+                BlobToKzgCommitment::FORMAT,
+                format!("input: {{blob: '{twos}'}}\noutput: '{}'\n", g1(3)),
+            ),
+            vector(
+                ComputeCells::FORMAT,
+                "input: {blob: '0x00'}\noutput: ['0x00']\n".to_string(),
+            ),
+            vector("unknown", String::new()),
+        ];
+        let report = run_vectors(&vectors, &kzg_settings);
+        assert_eq!(report.failures.len(), 3);
+        assert!(report.failures[0].reason.starts_with("expected"));
+        assert!(report.failures[1].reason.starts_with("does not parse"));
+    }
+
+    #[test]
+    fn test_compute_challenge() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        let blob = Blob::from_hex(&constant_blob(2)).unwrap();
+        let commitment = Bytes48::from_hex(&g1(2)).unwrap();
+        let mut challenge = compute_challenge(&blob, &commitment).to_bytes();
+        challenge.reverse();
+        let vectors = [
+            vector(
+                ComputeChallenge::FORMAT,
+                format!(
+                    "input: {{blob: '{}', commitment: '{}'}}\noutput: '{}'\n",
+                    constant_blob(2),
+                    g1(2),
+                    encode_hex(&challenge)
+                ),
+            ),
+            vector(
+                ComputeChallenge::FORMAT,
+                format!(
+                    "input: {{blob: '{}', commitment: '0x00'}}\noutput: null\n",
+                    constant_blob(2)
+                ),
+            ),
+        ];
+        let report = run_vectors(&vectors, &kzg_settings);
+        assert!(report.is_success(), "{report}");
+        assert_eq!(report.passed, 2);
+    }
+}
@@ -6,13 +6,33 @@ pub const NUM_G2_POINTS: usize = 65;
 pub const NUM_ROOTS_OF_UNITY: usize = 4096;
 pub const NUM_FIELD_ELEMENTS_PER_BLOB: usize = 4096;
 pub const BYTES_PER_BLOB: usize = NUM_FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+pub const NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL: usize = 4;
+pub const BYTES_PER_BLOB_MINIMAL: usize =
+    NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL * BYTES_PER_FIELD_ELEMENT;
+/// The number of data bytes packed into a field element, below a zero top byte.
+pub const USABLE_BYTES_PER_FIELD_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
 pub const BYTES_PER_COMMITMENT: usize = 48;
 pub const BYTES_PER_PROOF: usize = 48;
+/// The version byte of EIP-4844 versioned hashes of KZG commitments.
+pub const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
 pub const DOMAIN_STR_LENGTH: usize = 16;
 pub const CHALLENGE_INPUT_SIZE: usize =
     DOMAIN_STR_LENGTH + 16 + BYTES_PER_BLOB + BYTES_PER_COMMITMENT;
 pub const FIAT_SHAMIR_PROTOCOL_DOMAIN: &str = "FSBLOBVERIFY_V1_";
 pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &str = "RCKZGBATCH___V1_";
+/// Environment variable holding the path of a `trusted_setup.txt` to use instead of the
+/// embedded setup. Read by `EnvKzgSettings::from_env`.
+pub const KZG_TRUSTED_SETUP_ENV_VAR: &str = "OPENVM_KZG_TRUSTED_SETUP";
+/// `KzgSettings::digest` of the embedded mainnet trusted setup.
+pub const MAINNET_TRUSTED_SETUP_DIGEST: [u8; 32] = [
+    0xd6, 0x6f, 0x59, 0x7e, 0x84, 0x01, 0x4b, 0xe9, 0xa6, 0xdd, 0x6a, 0xcb, 0x6f, 0xcc, 0x08, 0xcf,
+    0xd1, 0xd1, 0x3f, 0xe0, 0xfd, 0x11, 0xeb, 0xd3, 0x1b, 0x00, 0x01, 0xcd, 0x22, 0x1d, 0x99, 0x9d,
+];
+/// `KzgSettings::digest` of the embedded trusted setup of the minimal preset.
+pub const MINIMAL_TRUSTED_SETUP_DIGEST: [u8; 32] = [
+    0x57, 0x59, 0xf6, 0xc5, 0x20, 0x54, 0x91, 0xfa, 0x45, 0xe2, 0x7c, 0x61, 0x8f, 0xa8, 0x7c, 0x66,
+    0x20, 0x77, 0x7d, 0x2d, 0xb8, 0x73, 0x2e, 0x17, 0x73, 0xa0, 0x4c, 0xba, 0x9c, 0x0f, 0x3f, 0x33,
+];
 
 pub const SCALE2_ROOT_OF_UNITY: [[u64; 4]; 32] = [
     [
@@ -0,0 +1,340 @@
+//! Verification on both backends at once, for checking that they agree.
+//!
+//! [`IntrinsicBackend`] and [`NativeBackend`] must give the same verdict on every input, valid or
+//! not. The functions here verify with both and return their [`Verdicts`].
+//!
+//! The tests run them over the consensus-spec vectors and random inputs on the host, where the
+//! intrinsic backend uses the OpenVM host-mode field implementations. That compares the
+//! arithmetic, but not the decoding: on the host both backends decode points with the native
+//! decoder. Only the `self-check` feature of the guest test program, which runs them in a guest,
+//! covers the point decoder of the intrinsic backend.
+
+use core::fmt;
+
+use crate::{
+    AsBlobRef, Bytes32, Bytes48, IntrinsicBackend, KzgError, KzgVerifier, NativeBackend,
+    VerificationOutcome, VerifierSetup,
+};
+
+/// The verdicts of the two backends on the same input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verdicts {
+    pub intrinsic: Result<VerificationOutcome, KzgError>,
+    pub native: Result<VerificationOutcome, KzgError>,
+}
+
+impl Verdicts {
+    pub fn agree(&self) -> bool {
+        self.intrinsic == self.native
+    }
+
+    /// Returns the verdict of both backends.
+    ///
+    /// Panics if the backends diverge.
+    pub fn expect_agreement(self) -> Result<VerificationOutcome, KzgError> {
+        assert!(self.agree(), "{self}");
+        self.native
+    }
+}
+
+impl fmt::Display for Verdicts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.agree() {
+            write!(f, "both backends: {:?}", self.native)
+        } else {
+            write!(
+                f,
+                "backends diverge: intrinsic {:?}, native {:?}",
+                self.intrinsic, self.native
+            )
+        }
+    }
+}
+
+/// [`crate::KzgProof::verify_kzg_proof`] on both backends.
+pub fn verify_kzg_proof(
+    commitment_bytes: &Bytes48,
+    z_bytes: &Bytes32,
+    y_bytes: &Bytes32,
+    proof_bytes: &Bytes48,
+    kzg_settings: &impl VerifierSetup,
+) -> Verdicts {
+    Verdicts {
+        intrinsic: KzgVerifier::<IntrinsicBackend>::verify_kzg_proof(
+            commitment_bytes,
+            z_bytes,
+            y_bytes,
+            proof_bytes,
+            kzg_settings,
+        ),
+        native: KzgVerifier::<NativeBackend>::verify_kzg_proof(
+            commitment_bytes,
+            z_bytes,
+            y_bytes,
+            proof_bytes,
+            kzg_settings,
+        ),
+    }
+}
+
+/// [`crate::KzgProof::verify_blob_kzg_proof`] on both backends.
+pub fn verify_blob_kzg_proof<B: AsBlobRef>(
+    blob: &B,
+    commitment_bytes: &Bytes48,
+    proof_bytes: &Bytes48,
+    kzg_settings: &impl VerifierSetup,
+) -> Verdicts {
+    Verdicts {
+        intrinsic: KzgVerifier::<IntrinsicBackend>::verify_blob_kzg_proof(
+            blob,
+            commitment_bytes,
+            proof_bytes,
+            kzg_settings,
+        ),
+        native: KzgVerifier::<NativeBackend>::verify_blob_kzg_proof(
+            blob,
+            commitment_bytes,
+            proof_bytes,
+            kzg_settings,
+        ),
+    }
+}
+
+/// [`crate::KzgProof::verify_blob_kzg_proof_batch`] on both backends.
+pub fn verify_blob_kzg_proof_batch<B: AsBlobRef>(
+    blobs: &[B],
+    commitments_bytes: &[Bytes48],
+    proofs_bytes: &[Bytes48],
+    kzg_settings: &impl VerifierSetup,
+) -> Verdicts {
+    Verdicts {
+        intrinsic: KzgVerifier::<IntrinsicBackend>::verify_blob_kzg_proof_batch(
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            kzg_settings,
+        ),
+        native: KzgVerifier::<NativeBackend>::verify_blob_kzg_proof_batch(
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            kzg_settings,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backend::KzgScalar,
+        test_utils::InsecureTrustedSetup,
+        test_vectors::{test_vectors, VerifyBlobKzgProof, VerifyBlobKzgProofBatch, VerifyKzgProof},
+        Blob, KzgPreset, KzgSettings, Minimal, BYTES_PER_BLOB_MINIMAL, BYTES_PER_FIELD_ELEMENT,
+    };
+    use alloc::vec::Vec;
+    use bls12_381::Scalar;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_spec_vectors_agree() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (test_file, test) in test_vectors::<VerifyKzgProof>() {
+            let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
+                test.input.get_commitment(),
+                test.input.get_z(),
+                test.input.get_y(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+            let verdicts = verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings);
+            let outcome = verdicts.expect_agreement();
+            assert_eq!(
+                VerificationOutcome::spec_output(&outcome),
+                test.get_output(),
+                "{test_file}"
+            );
+        }
+
+        for (test_file, test) in test_vectors::<VerifyBlobKzgProof>() {
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                continue;
+            };
+            let verdicts = verify_blob_kzg_proof(&blob, &commitment, &proof, &kzg_settings);
+            let outcome = verdicts.expect_agreement();
+            assert_eq!(
+                VerificationOutcome::spec_output(&outcome),
+                test.get_output(),
+                "{test_file}"
+            );
+        }
+
+        for (test_file, test) in test_vectors::<VerifyBlobKzgProofBatch>() {
+            let (Ok(blobs), Ok(commitments), Ok(proofs)) = (
+                test.input.get_blobs(),
+                test.input.get_commitments(),
+                test.input.get_proofs(),
+            ) else {
+                continue;
+            };
+            let verdicts =
+                verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings);
+            let outcome = verdicts.expect_agreement();
+            assert_eq!(
+                VerificationOutcome::spec_output(&outcome),
+                test.get_output(),
+                "{test_file}"
+            );
+        }
+    }
+
+    fn setup() -> InsecureTrustedSetup {
+        // The secret of the minimal setup.
+        InsecureTrustedSetup::generate(Scalar::from(1337u64), 4).unwrap()
+    }
+
+    /// A blob with canonical field elements, as the top byte of each is cleared.
+    fn blob(mut bytes: Vec<u8>) -> Blob<Minimal> {
+        for element in bytes.chunks_mut(BYTES_PER_FIELD_ELEMENT) {
+            element[0] = 0;
+        }
+        Blob::from_slice(&bytes).unwrap()
+    }
+
+    fn blob_bytes() -> impl Strategy<Value = Vec<u8>> {
+        proptest::collection::vec(any::<u8>(), BYTES_PER_BLOB_MINIMAL)
+    }
+
+    fn scalar() -> impl Strategy<Value = Scalar> {
+        any::<[u64; 4]>().prop_map(|limbs| {
+            let mut wide = [0u8; 64];
+            for (chunk, limb) in wide.chunks_mut(8).zip(limbs) {
+                chunk.copy_from_slice(&limb.to_le_bytes());
+            }
+            Scalar::from_bytes_wide(&wide)
+        })
+    }
+
+    /// Points that are not an honest proof, most of which do not decode.
+    fn bad_point() -> impl Strategy<Value = Bytes48> {
+        let mut off_subgroup = [0u8; 48];
+        off_subgroup[0] = 0x80;
+        off_subgroup[47] = 4;
+        let mut identity = [0u8; 48];
+        identity[0] = 0xc0;
+        prop_oneof![
+            any::<[u8; 32]>().prop_map(|bytes| {
+                let mut point = [0u8; 48];
+                point[..32].copy_from_slice(&bytes);
+                point[32..].copy_from_slice(&bytes[..16]);
+                Bytes48(point)
+            }),
+            Just(Bytes48(off_subgroup)),
+            Just(Bytes48(identity)),
+            Just(Bytes48([0xff; 48])),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_random_point_proofs_agree(bytes in blob_bytes(), z in scalar(), offset in 0u64..3) {
+            let setup = setup();
+            let kzg_settings = Minimal::kzg_settings();
+            let polynomial = blob(bytes).as_polynomial().unwrap();
+            let commitment = setup.commit(&polynomial).unwrap();
+            let (proof, y) = setup.compute_kzg_proof(&polynomial, z).unwrap();
+
+            // Only the honest evaluation verifies.
+            let outcome = verify_kzg_proof(
+                &commitment,
+                &Bytes32(z.to_be_bytes32()),
+                &Bytes32((y + Scalar::from(offset)).to_be_bytes32()),
+                &proof,
+                &kzg_settings,
+            )
+            .expect_agreement();
+            prop_assert_eq!(outcome, Ok(VerificationOutcome::from(offset == 0)));
+        }
+
+        #[test]
+        fn test_random_bytes_agree(
+            commitment in any::<[u8; 32]>(),
+            z in any::<[u8; 32]>(),
+            y in any::<[u8; 32]>(),
+            proof in bad_point(),
+        ) {
+            let kzg_settings = Minimal::kzg_settings();
+            let mut commitment_bytes = [0u8; 48];
+            commitment_bytes[16..].copy_from_slice(&commitment);
+            commitment_bytes[0] |= 0x80;
+            let verdicts = verify_kzg_proof(
+                &Bytes48(commitment_bytes),
+                &Bytes32(z),
+                &Bytes32(y),
+                &proof,
+                &kzg_settings,
+            );
+            prop_assert!(verdicts.agree(), "{}", verdicts);
+        }
+
+        #[test]
+        fn test_random_blobs_agree(
+            bytes in proptest::collection::vec(blob_bytes(), 1..4),
+            bad_proof in bad_point(),
+            swap in any::<bool>(),
+        ) {
+            let setup = setup();
+            let kzg_settings = Minimal::kzg_settings();
+            let blobs: Vec<Blob<Minimal>> = bytes.into_iter().map(blob).collect();
+            let (commitments, mut proofs): (Vec<_>, Vec<_>) = blobs
+                .iter()
+                .map(|blob| setup.compute_blob_kzg_proof(blob).unwrap())
+                .unzip();
+
+            for ((blob, commitment), proof) in blobs.iter().zip(&commitments).zip(&proofs) {
+                prop_assert_eq!(
+                    verify_blob_kzg_proof(blob, commitment, proof, &kzg_settings).expect_agreement(),
+                    Ok(VerificationOutcome::Valid)
+                );
+                let verdicts = verify_blob_kzg_proof(blob, commitment, &bad_proof, &kzg_settings);
+                prop_assert!(verdicts.agree(), "{}", verdicts);
+            }
+            prop_assert_eq!(
+                verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings)
+                    .expect_agreement(),
+                Ok(VerificationOutcome::Valid)
+            );
+
+            if swap && proofs.len() > 1 && proofs[0] != proofs[1] {
+                proofs.swap(0, 1);
+                prop_assert_eq!(
+                    verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings)
+                        .expect_agreement(),
+                    Ok(VerificationOutcome::InvalidProof)
+                );
+            }
+            proofs[0] = bad_proof;
+            let verdicts = verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings);
+            prop_assert!(verdicts.agree(), "{}", verdicts);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "backends diverge")]
+    fn test_divergence_is_reported() {
+        let verdicts = Verdicts {
+            intrinsic: Ok(VerificationOutcome::Valid),
+            native: Ok(VerificationOutcome::InvalidProof),
+        };
+        assert!(!verdicts.agree());
+        let _ = verdicts.expect_agreement();
+    }
+}
@@ -1,22 +1,33 @@
-use crate::enums::KzgError;
+use crate::backend::{DefaultBackend, KzgBackend};
+use crate::codec::{fmt_lower_hex, impl_hex, FromHex};
+use crate::enums::{InputError, KzgError};
 use crate::kzg_proof::safe_scalar_affine_from_bytes;
-use crate::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT};
+use crate::preset::{KzgPreset, Mainnet};
+use crate::{BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, VERSIONED_HASH_VERSION_KZG};
 
-use alloc::{string::ToString, vec::Vec};
+use alloc::{boxed::Box, vec::Vec};
 use bls12_381::Scalar;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 macro_rules! define_bytes_type {
     ($name:ident, $size:expr) => {
         #[repr(C, align(16))]
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
         pub struct $name(pub(crate) [u8; $size]);
 
         impl $name {
             pub fn from_slice(slice: &[u8]) -> Result<Self, KzgError> {
                 if slice.len() != $size {
-                    return Err(KzgError::InvalidBytesLength(
-                        "Invalid slice length".to_string(),
-                    ));
+                    return Err(KzgError::InvalidBytesLength {
+                        expected: $size,
+                        actual: slice.len(),
+                    });
                 }
                 let mut bytes = [0u8; $size];
                 bytes.copy_from_slice(slice);
@@ -37,14 +48,253 @@ macro_rules! define_bytes_type {
                 value.0
             }
         }
+
+        impl AsRef<$name> for $name {
+            fn as_ref(&self) -> &$name {
+                self
+            }
+        }
+
+        impl_hex!($name);
     };
 }
 
 define_bytes_type!(Bytes32, 32);
 define_bytes_type!(Bytes48, 48);
-define_bytes_type!(Blob, BYTES_PER_BLOB);
 
-impl Blob {
+/// Defines a newtype over `$bytes` whose constructors only accept bytes that pass `$validate`, so
+/// that values of different meanings cannot be passed for one another.
+macro_rules! define_validated_type {
+    ($(#[$doc:meta])* $name:ident($bytes:ident), $validate:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name($bytes);
+
+        impl $name {
+            pub fn new(bytes: $bytes) -> Result<Self, KzgError> {
+                let validate: fn(&$bytes) -> Result<(), KzgError> = $validate;
+                validate(&bytes)?;
+                Ok(Self(bytes))
+            }
+
+            pub fn from_slice(slice: &[u8]) -> Result<Self, KzgError> {
+                Self::new($bytes::from_slice(slice)?)
+            }
+
+            pub fn as_bytes(&self) -> &$bytes {
+                &self.0
+            }
+
+            pub fn as_slice(&self) -> &[u8] {
+                self.0.as_slice()
+            }
+        }
+
+        impl TryFrom<$bytes> for $name {
+            type Error = KzgError;
+
+            fn try_from(bytes: $bytes) -> Result<Self, KzgError> {
+                Self::new(bytes)
+            }
+        }
+
+        impl From<$name> for $bytes {
+            fn from(value: $name) -> $bytes {
+                value.0
+            }
+        }
+
+        impl AsRef<$bytes> for $name {
+            fn as_ref(&self) -> &$bytes {
+                &self.0
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let bytes = $bytes::deserialize(deserializer)?;
+                Self::new(bytes).map_err(serde::de::Error::custom)
+            }
+        }
+
+        impl_hex!($name);
+    };
+}
+
+define_validated_type!(
+    /// A compressed G1 point that decodes to a KZG commitment.
+    KzgCommitment(Bytes48),
+    |bytes| DefaultBackend::decode_g1(bytes).map(|_| ())
+);
+
+define_validated_type!(
+    /// A compressed G1 point that decodes to a KZG proof.
+    KzgProofBytes(Bytes48),
+    |bytes| DefaultBackend::decode_g1(bytes).map(|_| ())
+);
+
+define_validated_type!(
+    /// A big-endian scalar field element in canonical form, such as an evaluation point `z` or
+    /// value `y`.
+    FieldElement(Bytes32),
+    |bytes| safe_scalar_affine_from_bytes(bytes).map(|_| ())
+);
+
+define_validated_type!(
+    /// The EIP-4844 versioned hash of a KZG commitment: its SHA-256 with the first byte replaced
+    /// by [`VERSIONED_HASH_VERSION_KZG`].
+    VersionedHash(Bytes32),
+    |bytes| {
+        if bytes.0[0] == VERSIONED_HASH_VERSION_KZG {
+            Ok(())
+        } else {
+            Err(KzgError::BadArgs(InputError::VersionedHashVersion {
+                version: bytes.0[0],
+            }))
+        }
+    }
+);
+
+impl KzgCommitment {
+    pub fn to_versioned_hash(&self) -> VersionedHash {
+        let mut hash: [u8; 32] = Sha256::digest(self.as_slice()).into();
+        hash[0] = VERSIONED_HASH_VERSION_KZG;
+        VersionedHash(Bytes32(hash))
+    }
+}
+
+impl FieldElement {
+    pub fn to_scalar(&self) -> Scalar {
+        safe_scalar_affine_from_bytes(&self.0).expect("validated on construction")
+    }
+}
+
+impl From<Scalar> for FieldElement {
+    fn from(scalar: Scalar) -> Self {
+        let mut bytes = scalar.to_bytes();
+        bytes.reverse();
+        Self(Bytes32(bytes))
+    }
+}
+
+/// A blob of `P::FIELD_ELEMENTS_PER_BLOB` big-endian field elements. Defaults to the mainnet
+/// preset.
+///
+/// The bytes are kept on the heap so that a blob is never built or moved on the stack. See
+/// [`BlobRef`] for a view of a blob in existing memory.
+#[derive(Debug, Clone)]
+pub struct Blob<P: KzgPreset = Mainnet>(Box<[u8]>, PhantomData<P>);
+
+impl<P: KzgPreset> Blob<P> {
+    /// Copies the bytes into a blob. As with [`BlobRef::from_slice`], only the length is checked.
+    pub fn from_slice(slice: &[u8]) -> Result<Self, KzgError> {
+        BlobRef::<P>::from_slice(slice).map(|blob| blob.to_blob())
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn reverse_bytes(&mut self) {
+        self.0.reverse();
+    }
+
+    pub fn as_polynomial(&self) -> Result<Vec<Scalar>, KzgError> {
+        self.as_blob_ref().as_polynomial()
+    }
+}
+
+// Not derived, as that would require `P: PartialEq + Eq + Hash`.
+impl<P: KzgPreset> PartialEq for Blob<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<P: KzgPreset> Eq for Blob<P> {}
+
+impl<P: KzgPreset> Hash for Blob<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<P: KzgPreset> TryFrom<Vec<u8>> for Blob<P> {
+    type Error = KzgError;
+
+    /// Takes ownership of the bytes without copying them.
+    fn try_from(bytes: Vec<u8>) -> Result<Self, KzgError> {
+        BlobRef::<P>::from_slice(&bytes)?;
+        Ok(Blob(bytes.into_boxed_slice(), PhantomData))
+    }
+}
+
+/// A borrowed blob, such as the bytes read from the OpenVM input stream with
+/// `openvm::io::read_vec`. Only the length is checked on construction and nothing is copied.
+#[derive(Debug)]
+pub struct BlobRef<'a, P: KzgPreset = Mainnet>(&'a [u8], PhantomData<P>);
+
+// Not derived, as that would require `P: Copy`.
+impl<P: KzgPreset> Clone for BlobRef<'_, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: KzgPreset> Copy for BlobRef<'_, P> {}
+
+impl<P: KzgPreset> PartialEq for BlobRef<'_, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<P: KzgPreset> Eq for BlobRef<'_, P> {}
+
+impl<P: KzgPreset> Hash for BlobRef<'_, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<'a, P: KzgPreset> BlobRef<'a, P> {
+    /// Borrows `slice` as a blob if it has the length of a blob of `P`.
+    ///
+    /// The field elements are not checked here, but by [`BlobRef::as_polynomial`] and the verify
+    /// functions, which report a non-canonical element as
+    /// [`VerificationOutcome::NonCanonicalFieldElement`](crate::VerificationOutcome) rather than
+    /// failing to build the blob.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Self, KzgError> {
+        if slice.len() != P::BYTES_PER_BLOB {
+            return Err(KzgError::InvalidBytesLength {
+                expected: P::BYTES_PER_BLOB,
+                actual: slice.len(),
+            });
+        }
+        Ok(BlobRef(slice, PhantomData))
+    }
+
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Copies the bytes into an owned [`Blob`].
+    pub fn to_blob(&self) -> Blob<P> {
+        Blob(self.0.into(), PhantomData)
+    }
+
     pub fn as_polynomial(&self) -> Result<Vec<Scalar>, KzgError> {
         self.0
             .chunks(BYTES_PER_FIELD_ELEMENT)
@@ -55,6 +305,63 @@ impl Blob {
     }
 }
 
+/// A blob in any representation, owned or borrowed, as accepted by the blob verification
+/// functions.
+pub trait AsBlobRef {
+    type Preset: KzgPreset;
+
+    fn as_blob_ref(&self) -> BlobRef<'_, Self::Preset>;
+}
+
+impl<P: KzgPreset> AsBlobRef for Blob<P> {
+    type Preset = P;
+
+    fn as_blob_ref(&self) -> BlobRef<'_, P> {
+        BlobRef(&self.0, PhantomData)
+    }
+}
+
+impl<P: KzgPreset> AsBlobRef for BlobRef<'_, P> {
+    type Preset = P;
+
+    fn as_blob_ref(&self) -> BlobRef<'_, P> {
+        *self
+    }
+}
+
+impl<P: KzgPreset> FromHex for Blob<P> {
+    fn from_hex(hex: &str) -> Result<Self, KzgError> {
+        Self::try_from(crate::codec::decode_hex(hex)?)
+    }
+}
+
+impl<P: KzgPreset> core::str::FromStr for Blob<P> {
+    type Err = KzgError;
+
+    fn from_str(s: &str) -> Result<Self, KzgError> {
+        Self::from_hex(s)
+    }
+}
+
+impl<P: KzgPreset> fmt::Display for Blob<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self)
+    }
+}
+
+impl<P: KzgPreset> fmt::LowerHex for Blob<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_lower_hex(self.as_slice(), f)
+    }
+}
+
+impl From<Blob> for Box<[u8; BYTES_PER_BLOB]> {
+    /// Keeps the bytes on the heap, without copying them.
+    fn from(value: Blob) -> Box<[u8; BYTES_PER_BLOB]> {
+        value.0.try_into().expect("a blob has BYTES_PER_BLOB bytes")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -68,4 +375,58 @@ mod tests {
         let bytes = crate::dtypes::Bytes48::from_slice(&[0u8; 48]).unwrap();
         assert_eq!(bytes.0.len(), 48);
     }
+
+    #[test]
+    fn test_typed_bytes() {
+        use crate::{Bytes32, Bytes48, FieldElement, KzgCommitment, KzgProofBytes, VersionedHash};
+        use bls12_381::Scalar;
+
+        let identity = "0xc00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+        let commitment: KzgCommitment = identity.parse().unwrap();
+        assert_eq!(commitment.to_string(), identity);
+        assert_eq!(
+            commitment.to_versioned_hash().to_string(),
+            "0x010657f37554c781402a22917dee2f75def7ab966d7b770905398eba3c444014"
+        );
+        // Not a point on the curve.
+        assert!(KzgProofBytes::from_slice(&[0u8; 48]).is_err());
+        assert!("0xc0".parse::<KzgProofBytes>().is_err());
+
+        let y = FieldElement::from(Scalar::from(5u64));
+        assert_eq!(y.to_scalar(), Scalar::from(5u64));
+        let modulus = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+        assert!(modulus.parse::<FieldElement>().is_err());
+
+        assert!(VersionedHash::new(Bytes32::from_slice(&[0u8; 32]).unwrap()).is_err());
+
+        let yaml = serde_yaml::to_string(&commitment).unwrap();
+        assert_eq!(
+            serde_yaml::from_str::<KzgCommitment>(&yaml).unwrap(),
+            commitment
+        );
+        let invalid = serde_yaml::to_string(&Bytes48::from_slice(&[0u8; 48]).unwrap()).unwrap();
+        assert!(serde_yaml::from_str::<KzgCommitment>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_blob_preset_length() {
+        use crate::{AsBlobRef, Blob, BlobRef, Minimal, BYTES_PER_BLOB_MINIMAL};
+
+        let blob = Blob::<Minimal>::from_slice(&[0u8; BYTES_PER_BLOB_MINIMAL]).unwrap();
+        assert_eq!(blob.as_polynomial().unwrap().len(), 4);
+        assert!(Blob::<Minimal>::from_slice(&[0u8; BYTES_PER_BLOB_MINIMAL + 1]).is_err());
+
+        let bytes = [1u8; BYTES_PER_BLOB_MINIMAL].to_vec();
+        let blob_ref = BlobRef::<Minimal>::from_slice(&bytes).unwrap();
+        assert_eq!(blob_ref.as_slice().as_ptr(), bytes.as_ptr());
+        assert_eq!(blob_ref.to_blob().as_slice(), &bytes[..]);
+        assert_eq!(blob_ref.to_blob().as_blob_ref(), blob_ref);
+        assert_eq!(
+            Blob::<Minimal>::try_from(bytes.clone()).unwrap(),
+            blob_ref.to_blob()
+        );
+        let blob = Blob::<Minimal>::try_from(bytes).unwrap();
+        assert_eq!(blob.as_blob_ref().as_polynomial().unwrap().len(), 4);
+        assert!(BlobRef::<Minimal>::from_slice(&[0u8; 3]).is_err());
+    }
 }
@@ -1,30 +1,549 @@
-extern crate alloc;
-
-use alloc::string::String;
 use core::fmt;
 
-#[derive(Debug, Clone)]
+/// The errors of this crate. None of them allocate, so that failing in a guest is cheap, and the
+/// cause of each can be matched on.
+///
+/// The error enums are `#[non_exhaustive]`: new causes may be added, and some only exist with a
+/// feature, such as `TrustedSetupError::Read` with `std`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum KzgError {
     /// The supplied data is invalid in some way.
-    BadArgs(String),
+    BadArgs(InputError),
     /// Internal error - this should never occur.
     InternalError,
     /// The provided bytes are of incorrect length.
-    InvalidBytesLength(String),
+    InvalidBytesLength { expected: usize, actual: usize },
     /// Error when converting from hex to bytes.
-    InvalidHexFormat(String),
+    InvalidHexFormat(hex::FromHexError),
     /// The provided trusted setup params are invalid.
-    InvalidTrustedSetup(String),
+    InvalidTrustedSetup(TrustedSetupError),
+    /// Data packed into blobs is not a valid encoding.
+    InvalidBlobEncoding(BlobEncodingError),
+    /// A powers-of-tau ceremony or an insecure setup failed.
+    #[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+    Ceremony(CeremonyError),
+}
+
+/// Why supplied data is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InputError {
+    /// A scalar is not the canonical big-endian encoding of a field element.
+    NonCanonicalScalar,
+    /// The named scalar of a proof is not reduced.
+    UnreducedScalar(&'static str),
+    /// A field element of a blob is not canonical.
+    NonCanonicalBlobElement { index: usize },
+    /// A compressed G1 point is not a valid encoding of a point on the curve: its flags are
+    /// invalid, its x-coordinate is not reduced, or no point has that x-coordinate.
+    InvalidPointEncoding,
+    /// A G1 point is on the curve but not in the prime order subgroup.
+    PointNotInSubgroup,
+    /// A versioned hash does not have the KZG version byte.
+    VersionedHashVersion { version: u8 },
+    /// Batch verification got different numbers of blobs, commitments and proofs.
+    BatchLengthMismatch {
+        blobs: usize,
+        commitments: usize,
+        proofs: usize,
+    },
+    /// A polynomial does not have one evaluation per root of unity.
+    PolynomialLength { len: usize, num_roots: usize },
+    /// A polynomial has more coefficients than there are roots of unity.
+    TooManyCoefficients { len: usize, num_roots: usize },
+    /// The shift of a coset FFT is zero.
+    ZeroCosetShift,
+    /// An FFT size is not a power of two no larger than the number of roots of unity.
+    FftSize { size: usize, num_roots: usize },
+    /// The number of roots of unity to compute is not a power of two below `2^32`.
+    RootsOfUnityCount { n: usize },
+}
+
+/// Why a trusted setup is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrustedSetupError {
+    /// Blob verification needs the roots of unity, which the settings do not have.
+    MissingRootsOfUnity,
+    /// The trusted setup is not for the domain of the preset.
+    RootsOfUnityCount { expected: usize, actual: usize },
+    /// The trusted setup does not have one G1 point per field element of the domain.
+    G1PointCount { expected: usize, actual: usize },
+    /// Verifier settings must have no G1 points and two G2 points.
+    VerifierSettingsShape,
+    /// The digest of the setup does not match the expected one.
+    DigestMismatch,
+    /// A G1 point of the setup is not a valid compressed point of the G1 subgroup.
+    InvalidG1Point,
+    /// A G2 point of the setup is not a valid compressed point of the G2 subgroup.
+    InvalidG2Point,
+    /// A root of unity of the setup is not a canonical scalar.
+    InvalidRootOfUnity,
+    /// A `trusted_setup.txt` does not start with the named number of points.
+    MissingCount(&'static str),
+    /// A setup needs a power of two of G1 points and at least two G2 points.
+    PointCounts {
+        num_g1_points: usize,
+        num_g2_points: usize,
+    },
+    /// A `trusted_setup.txt` ends before all of its points.
+    MissingPoints,
+    /// The G1 points are not in monomial form.
+    NotMonomialForm,
+    /// The trusted setup file could not be read.
+    #[cfg(feature = "std")]
+    Read(std::io::ErrorKind),
+}
+
+/// Why a powers-of-tau ceremony, or an insecure setup generated for tests, failed.
+#[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CeremonyError {
+    /// A ceremony transcript is not valid JSON of the expected shape.
+    InvalidJson { line: usize, column: usize },
+    /// A ceremony transcript does not have the number of powers it declares.
+    PowersCount {
+        expected_g1: usize,
+        expected_g2: usize,
+        actual_g1: usize,
+        actual_g2: usize,
+    },
+    /// A ceremony transcript cannot be truncated to more powers than it has.
+    TranscriptTruncation {
+        num_g1_powers: usize,
+        num_g2_powers: usize,
+        to_num_g1_powers: usize,
+        to_num_g2_powers: usize,
+    },
+    /// Powers of tau need at least two G1 and G2 powers.
+    TooFewPowers,
+    /// The powers of tau do not start at the generators.
+    NotAtGenerators,
+    /// τ is zero.
+    ZeroTau,
+    /// The G1 powers are not consecutive powers of the τ of the G2 powers.
+    G1PowersNotConsecutive,
+    /// The G2 powers are not consecutive powers of the same τ.
+    G2PowersNotConsecutive,
+    /// Only a power of two of points can be converted to Lagrange form.
+    LagrangeSize { n: usize },
+    /// The last running product of a ceremony is not `[τ]₁`.
+    LastRunningProduct,
+    /// A ceremony witness has more proofs of knowledge than contributions.
+    ExtraProofsOfKnowledge,
+    /// The proof of knowledge of the secret of a contribution is invalid.
+    InvalidProofOfKnowledge { contribution: usize },
+    /// A proof of knowledge is not a valid encoding.
+    InvalidProofOfKnowledgeEncoding,
+    /// Two ceremony transcripts have different sizes.
+    TranscriptSizes,
+    /// A ceremony transcript does not extend the previous one.
+    NotAnExtension,
+    /// A new contribution to a ceremony has no proof of knowledge.
+    MissingProofOfKnowledge,
+    /// A ceremony transcript has no sub-ceremony of the size of the trusted setup.
+    MissingSubCeremony { num_g1_powers: usize },
+    /// The G2 powers of a ceremony are not those of the trusted setup.
+    G2PowersMismatch,
+    /// The G1 powers of a ceremony, in Lagrange form, are not those of the trusted setup.
+    LagrangeMismatch,
+    /// A ceremony witness does not have one running product per public key.
+    RunningProductCount,
+    /// The running products of a ceremony witness do not start at the generators.
+    RunningProductsNotAtGenerators,
+    /// A contribution to a ceremony has a zero secret.
+    ZeroSecret,
+    /// The running products of a ceremony witness do not match the contributions.
+    RunningProductsMismatch,
+    /// The secret of an insecure setup is a root of unity.
+    SecretIsRootOfUnity,
+    /// An insecure setup cannot open a polynomial at its own secret.
+    OpeningAtSecret,
+}
+
+/// Why blobs are not a valid encoding of packed data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BlobEncodingError {
+    /// A field element of packed data has a non-zero top byte.
+    NonZeroTopByte { blob: usize, field_element: usize },
+    /// Packed data needs at least one blob for its length.
+    NoBlobs,
+    /// The length prefix is larger than the blobs can hold.
+    LengthExceedsCapacity { length: u64, num_blobs: usize },
+    /// The data length needs a different number of blobs.
+    BlobCount {
+        data_len: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// The padding after packed data is not zero.
+    NonZeroPadding,
+    /// Data is too long for an OP Stack blob.
+    InputTooLarge { len: usize, max: usize },
+    /// An OP Stack blob has an unknown encoding version.
+    Version { expected: u8, actual: u8 },
+    /// The length of an OP Stack blob is too large.
+    Length { len: usize, max: usize },
+    /// An OP Stack blob has data past its length.
+    ExtraneousData { field_element: usize },
+    /// An OP Stack blob has non-zero bytes past its last round.
+    ExtraneousBytes { byte: usize },
+    /// A field element of an OP Stack blob has one of its two top bits set.
+    InvalidFieldElement { field_element: usize },
+}
+
+/// The verdict of a proof verification, telling malformed inputs apart from a well-formed proof
+/// that does not verify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerificationOutcome {
+    /// The inputs are well formed and the proof is valid.
+    Valid,
+    /// The inputs are well formed but the proof does not verify.
+    InvalidProof,
+    /// A point is not a valid compressed encoding of a point on the curve.
+    MalformedEncoding,
+    /// A scalar or a field element of a blob is not canonical.
+    NonCanonicalFieldElement,
+    /// A point is on the curve but not in the G1 subgroup.
+    PointNotInSubgroup,
+}
+
+impl VerificationOutcome {
+    pub fn is_valid(self) -> bool {
+        self == Self::Valid
+    }
+
+    /// Returns whether the inputs decoded, so that the proof was checked. The consensus-spec
+    /// tests expect an output of `true` or `false` exactly in this case, and `null` otherwise.
+    pub fn is_well_formed(self) -> bool {
+        matches!(self, Self::Valid | Self::InvalidProof)
+    }
+
+    /// Returns the output of a verifier in the consensus-spec tests: whether the proof is valid,
+    /// or `None` (`null`) if the inputs were rejected.
+    pub fn spec_output(result: &Result<Self, KzgError>) -> Option<bool> {
+        match result {
+            Ok(outcome) if outcome.is_well_formed() => Some(outcome.is_valid()),
+            _ => None,
+        }
+    }
+
+    /// Returns the outcome of inputs rejected with `error`, or `None` if the error is not about
+    /// the encoding of the inputs, such as a mismatched trusted setup.
+    pub fn from_error(error: &KzgError) -> Option<Self> {
+        match error {
+            KzgError::BadArgs(
+                InputError::NonCanonicalScalar
+                | InputError::UnreducedScalar(_)
+                | InputError::NonCanonicalBlobElement { .. },
+            ) => Some(Self::NonCanonicalFieldElement),
+            KzgError::BadArgs(InputError::InvalidPointEncoding) => Some(Self::MalformedEncoding),
+            KzgError::BadArgs(InputError::PointNotInSubgroup) => Some(Self::PointNotInSubgroup),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for VerificationOutcome {
+    fn from(valid: bool) -> Self {
+        if valid {
+            Self::Valid
+        } else {
+            Self::InvalidProof
+        }
+    }
 }
 
 impl fmt::Display for KzgError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::BadArgs(s)
-            | Self::InvalidBytesLength(s)
-            | Self::InvalidHexFormat(s)
-            | Self::InvalidTrustedSetup(s) => f.write_str(s),
+            Self::BadArgs(e) => e.fmt(f),
             Self::InternalError => f.write_str("Internal error"),
+            Self::InvalidBytesLength { expected, actual } => write!(
+                f,
+                "Invalid slice length: expected {expected} bytes, found {actual}"
+            ),
+            Self::InvalidHexFormat(e) => write!(f, "Failed to decode hex: {e}"),
+            Self::InvalidTrustedSetup(e) => e.fmt(f),
+            Self::InvalidBlobEncoding(e) => e.fmt(f),
+            #[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+            Self::Ceremony(e) => e.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonCanonicalScalar => f.write_str("Failed to parse Scalar from bytes32"),
+            Self::UnreducedScalar(name) => write!(f, "Scalar {name} is not reduced"),
+            Self::NonCanonicalBlobElement { index } => write!(
+                f,
+                "Failed to parse Scalar from bytes32 at blob field element {index}"
+            ),
+            Self::InvalidPointEncoding => f.write_str("Failed to parse G1Affine from bytes"),
+            Self::PointNotInSubgroup => f.write_str(
+                "Failed to parse G1Affine from bytes: the point is not in the G1 subgroup",
+            ),
+            Self::VersionedHashVersion { version } => {
+                write!(f, "Versioned hash version {version} is not 1")
+            }
+            Self::BatchLengthMismatch {
+                blobs,
+                commitments,
+                proofs,
+            } => write!(
+                f,
+                "The number of blobs, commitments and proofs must be equal, found {blobs}, \
+                 {commitments} and {proofs}"
+            ),
+            Self::PolynomialLength { .. } => {
+                f.write_str("The polynomial length must match the number of roots of unity")
+            }
+            Self::TooManyCoefficients { .. } => {
+                f.write_str("The polynomial has more coefficients than roots of unity")
+            }
+            Self::ZeroCosetShift => f.write_str("The coset shift must be non-zero"),
+            Self::FftSize { size, num_roots } => write!(
+                f,
+                "Cannot transform {size} values over {num_roots} roots of unity, both must be \
+                 powers of two and the values no more than the roots"
+            ),
+            Self::RootsOfUnityCount { n } => write!(
+                f,
+                "Cannot compute {n} roots of unity, the number must be a power of two below 2^32"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for TrustedSetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRootsOfUnity => {
+                f.write_str("Blob verification requires the roots of unity of the trusted setup")
+            }
+            Self::RootsOfUnityCount { expected, actual } => write!(
+                f,
+                "Expected a trusted setup with {expected} roots of unity, found {actual}"
+            ),
+            Self::G1PointCount { expected, actual } => write!(
+                f,
+                "Expected a trusted setup with {expected} G1 points, found {actual}"
+            ),
+            Self::VerifierSettingsShape => {
+                f.write_str("Verifier settings must have no G1 points and two G2 points")
+            }
+            Self::DigestMismatch => f.write_str("Trusted setup digest mismatch"),
+            Self::InvalidG1Point => f.write_str("Invalid G1 point in trusted setup"),
+            Self::InvalidG2Point => f.write_str("Invalid G2 point in trusted setup"),
+            Self::InvalidRootOfUnity => f.write_str("Invalid root of unity in trusted setup"),
+            Self::MissingCount(name) => write!(f, "Missing number of {name}"),
+            Self::PointCounts {
+                num_g1_points,
+                num_g2_points,
+            } => write!(
+                f,
+                "Expected a power of two of G1 points and at least 2 G2 points, found \
+                 {num_g1_points} and {num_g2_points}"
+            ),
+            Self::MissingPoints => f.write_str("Trusted setup is missing points"),
+            Self::NotMonomialForm => f.write_str("The G1 points are not in monomial form"),
+            #[cfg(feature = "std")]
+            Self::Read(kind) => write!(f, "Failed to read the trusted setup: {kind}"),
+        }
+    }
+}
+
+#[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+impl fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidJson { line, column } => write!(
+                f,
+                "Failed to parse ceremony transcript at line {line}, column {column}"
+            ),
+            Self::PowersCount {
+                expected_g1,
+                expected_g2,
+                actual_g1,
+                actual_g2,
+            } => write!(
+                f,
+                "Expected {expected_g1} G1 and {expected_g2} G2 powers, found {actual_g1} and \
+                 {actual_g2}"
+            ),
+            Self::LastRunningProduct => f.write_str("The last running product does not match [τ]₁"),
+            Self::ExtraProofsOfKnowledge => {
+                f.write_str("The witness has more proofs of knowledge than contributions")
+            }
+            Self::InvalidProofOfKnowledge { contribution } => write!(
+                f,
+                "Invalid proof of knowledge for contribution {contribution}"
+            ),
+            Self::InvalidProofOfKnowledgeEncoding => f.write_str("Invalid proof of knowledge"),
+            Self::TranscriptSizes => f.write_str("The transcripts have different sizes"),
+            Self::NotAnExtension => f.write_str("The transcript does not extend the previous one"),
+            Self::MissingProofOfKnowledge => {
+                f.write_str("Every new contribution must have a proof of knowledge")
+            }
+            Self::TooFewPowers => f.write_str("At least two G1 and G2 powers are required"),
+            Self::LagrangeSize { n } => write!(
+                f,
+                "Cannot convert {n} points to Lagrange form, the number must be a power of two"
+            ),
+            Self::NotAtGenerators => f.write_str("The powers of tau must start at the generators"),
+            Self::ZeroTau => f.write_str("τ must not be zero"),
+            Self::G1PowersNotConsecutive => {
+                f.write_str("The G1 powers are not consecutive powers of τ")
+            }
+            Self::G2PowersNotConsecutive => {
+                f.write_str("The G2 powers are not consecutive powers of τ")
+            }
+            Self::MissingSubCeremony { num_g1_powers } => write!(
+                f,
+                "The transcript has no sub-ceremony of {num_g1_powers} G1 powers"
+            ),
+            Self::G2PowersMismatch => f.write_str("The G2 powers do not match the trusted setup"),
+            Self::LagrangeMismatch => {
+                f.write_str("The Lagrange form of the G1 powers does not match the trusted setup")
+            }
+            Self::RunningProductCount => {
+                f.write_str("The witness must have one running product per public key")
+            }
+            Self::RunningProductsNotAtGenerators => {
+                f.write_str("The witness must start at the generators")
+            }
+            Self::ZeroSecret => f.write_str("A contribution has a zero secret"),
+            Self::RunningProductsMismatch => {
+                f.write_str("The running products do not match the contributions")
+            }
+            Self::TranscriptTruncation {
+                num_g1_powers,
+                num_g2_powers,
+                to_num_g1_powers,
+                to_num_g2_powers,
+            } => write!(
+                f,
+                "Cannot truncate {num_g1_powers} G1 and {num_g2_powers} G2 powers to \
+                 {to_num_g1_powers} and {to_num_g2_powers}"
+            ),
+            Self::SecretIsRootOfUnity => f.write_str("The secret must not be a root of unity"),
+            Self::OpeningAtSecret => f.write_str("Cannot open a polynomial at the secret"),
+        }
+    }
+}
+
+impl fmt::Display for VerificationOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Valid => "The proof is valid",
+            Self::InvalidProof => "The proof is invalid",
+            Self::MalformedEncoding => "A point is not a valid compressed encoding",
+            Self::NonCanonicalFieldElement => "A field element is not canonical",
+            Self::PointNotInSubgroup => "A point is not in the G1 subgroup",
+        })
+    }
+}
+
+impl fmt::Display for BlobEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonZeroTopByte {
+                blob,
+                field_element,
+            } => write!(
+                f,
+                "Field element {field_element} of blob {blob} has a non-zero top byte"
+            ),
+            Self::NoBlobs => f.write_str("There must be at least one blob"),
+            Self::LengthExceedsCapacity { length, num_blobs } => write!(
+                f,
+                "The encoded length {length} exceeds the capacity of {num_blobs} blobs"
+            ),
+            Self::BlobCount {
+                data_len,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{data_len} bytes are encoded in {expected} blobs, not {actual}"
+            ),
+            Self::NonZeroPadding => f.write_str("The padding after the data must be zero"),
+            Self::InputTooLarge { len, max } => {
+                write!(f, "Blob input too large: {len} bytes, the maximum is {max}")
+            }
+            Self::Version { expected, actual } => write!(
+                f,
+                "Invalid blob encoding version: expected {expected}, got {actual}"
+            ),
+            Self::Length { len, max } => {
+                write!(f, "Invalid blob length {len}, the maximum is {max}")
+            }
+            Self::ExtraneousData { field_element } => write!(
+                f,
+                "Extraneous data in the blob after field element {field_element}"
+            ),
+            Self::ExtraneousBytes { byte } => {
+                write!(f, "Extraneous data in the blob after byte {byte}")
+            }
+            Self::InvalidFieldElement { field_element } => write!(
+                f,
+                "Invalid field element {field_element}: its two highest bits must be zero"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for KzgError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidHexFormat(e) => Some(e),
+            _ => None,
         }
     }
 }
+
+impl core::error::Error for InputError {}
+
+impl core::error::Error for TrustedSetupError {}
+
+impl core::error::Error for BlobEncodingError {}
+
+#[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+impl core::error::Error for CeremonyError {}
+
+impl From<InputError> for KzgError {
+    fn from(e: InputError) -> Self {
+        Self::BadArgs(e)
+    }
+}
+
+impl From<TrustedSetupError> for KzgError {
+    fn from(e: TrustedSetupError) -> Self {
+        Self::InvalidTrustedSetup(e)
+    }
+}
+
+impl From<BlobEncodingError> for KzgError {
+    fn from(e: BlobEncodingError) -> Self {
+        Self::InvalidBlobEncoding(e)
+    }
+}
+
+#[cfg(any(feature = "ceremony", feature = "test-utils", test))]
+impl From<CeremonyError> for KzgError {
+    fn from(e: CeremonyError) -> Self {
+        Self::Ceremony(e)
+    }
+}
+
+impl From<hex::FromHexError> for KzgError {
+    fn from(e: hex::FromHexError) -> Self {
+        Self::InvalidHexFormat(e)
+    }
+}
@@ -0,0 +1,221 @@
+//! Radix-2 FFTs over the roots of unity of a trusted setup, converting polynomials between
+//! coefficient form and evaluation form, and the bit-reversal permutation that orders the roots.
+//!
+//! The transforms take the roots of unity as embedded, in bit-reversed order (see
+//! [`crate::get_roots_of_unity`]), and run on either `bls12_381::Scalar` or the OpenVM `Scalar`.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use bls12_381::Scalar;
+
+use crate::{InputError, KzgError};
+
+/// A scalar field element the transforms can run on.
+pub trait FftScalar: Clone + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    fn from_scalar(scalar: &Scalar) -> Self;
+}
+
+impl FftScalar for Scalar {
+    fn from_scalar(scalar: &Scalar) -> Self {
+        *scalar
+    }
+}
+
+impl FftScalar for openvm_pairing::bls12_381::Scalar {
+    fn from_scalar(scalar: &Scalar) -> Self {
+        use openvm_algebra_guest::IntMod;
+
+        Self::from_le_bytes_unchecked(&scalar.to_bytes())
+    }
+}
+
+/// Returns the position of `index` in the bit-reversal permutation of `n` elements.
+///
+/// Panics if `n` is not a power of two.
+pub fn reverse_bits(index: usize, n: usize) -> usize {
+    assert!(n.is_power_of_two(), "n must be a power of 2");
+    let unused_bits = usize::BITS - n.trailing_zeros();
+    index.reverse_bits().checked_shr(unused_bits).unwrap_or(0)
+}
+
+/// Applies the bit-reversal permutation, which is its own inverse.
+///
+/// Panics if the length is not a power of two.
+pub fn bit_reverse_in_place<T>(values: &mut [T]) {
+    let n = values.len();
+    for i in 0..n {
+        let r = reverse_bits(i, n);
+        if i < r {
+            values.swap(i, r);
+        }
+    }
+}
+
+/// Evaluates the polynomial with coefficients `values`, lowest degree first, at the powers
+/// `w^0, w^1, ...` of a primitive `values.len()`-th root of unity `w`, in place.
+///
+/// The length must be a power of two no larger than the number of `roots_of_unity`, which are in
+/// bit-reversed order. The evaluations are in natural order; bit-reverse them to get the order of
+/// a blob.
+pub fn fft<T: FftScalar>(values: &mut [T], roots_of_unity: &[Scalar]) -> Result<(), KzgError> {
+    fft_impl(values, roots_of_unity, false)
+}
+
+/// The inverse of [`fft`]: interpolates evaluations in natural order into coefficients.
+pub fn ifft<T: FftScalar>(values: &mut [T], roots_of_unity: &[Scalar]) -> Result<(), KzgError> {
+    fft_impl(values, roots_of_unity, true)?;
+    let inverse_size = Scalar::from(values.len() as u64).invert().unwrap();
+    scale_by_powers(
+        values,
+        &T::from_scalar(&inverse_size),
+        &T::from_scalar(&Scalar::one()),
+    );
+    Ok(())
+}
+
+/// [`fft`] over the coset `shift * <w>`, for evaluating outside of the domain of the roots of
+/// unity.
+pub fn coset_fft<T: FftScalar>(
+    values: &mut [T],
+    shift: &Scalar,
+    roots_of_unity: &[Scalar],
+) -> Result<(), KzgError> {
+    check_fft_size(values.len(), roots_of_unity.len())?;
+    let one = T::from_scalar(&Scalar::one());
+    scale_by_powers(values, &one, &T::from_scalar(shift));
+    fft(values, roots_of_unity)
+}
+
+/// The inverse of [`coset_fft`]. `shift` must be non-zero.
+pub fn coset_ifft<T: FftScalar>(
+    values: &mut [T],
+    shift: &Scalar,
+    roots_of_unity: &[Scalar],
+) -> Result<(), KzgError> {
+    let inverse_shift = Option::<Scalar>::from(shift.invert())
+        .ok_or(KzgError::BadArgs(InputError::ZeroCosetShift))?;
+    ifft(values, roots_of_unity)?;
+    let one = T::from_scalar(&Scalar::one());
+    scale_by_powers(values, &one, &T::from_scalar(&inverse_shift));
+    Ok(())
+}
+
+/// Checks that a transform of `size` elements can run over `num_roots` roots of unity.
+pub(crate) fn check_fft_size(size: usize, num_roots: usize) -> Result<(), KzgError> {
+    if !size.is_power_of_two() || !num_roots.is_power_of_two() || size > num_roots {
+        return Err(KzgError::BadArgs(InputError::FftSize { size, num_roots }));
+    }
+    Ok(())
+}
+
+fn fft_impl<T: FftScalar>(
+    values: &mut [T],
+    roots_of_unity: &[Scalar],
+    inverse: bool,
+) -> Result<(), KzgError> {
+    let size = values.len();
+    let num_roots = roots_of_unity.len();
+    check_fft_size(size, num_roots)?;
+
+    // twiddles[k] = w^k, or w^-k for the inverse, where w is a primitive size-th root of unity
+    let stride = num_roots / size;
+    let twiddles: Vec<T> = (0..size / 2)
+        .map(|k| {
+            let exponent = if inverse { (size - k) % size } else { k };
+            T::from_scalar(&roots_of_unity[reverse_bits(exponent * stride, num_roots)])
+        })
+        .collect();
+
+    bit_reverse_in_place(values);
+    let mut width = 2;
+    while width <= size {
+        let step = size / width;
+        for chunk in values.chunks_mut(width) {
+            let (lower, upper) = chunk.split_at_mut(width / 2);
+            for (j, (x, y)) in lower.iter_mut().zip(upper).enumerate() {
+                let t = y.clone() * twiddles[j * step].clone();
+                *y = x.clone() - t.clone();
+                *x = x.clone() + t;
+            }
+        }
+        width *= 2;
+    }
+    Ok(())
+}
+
+/// Multiplies `values[i]` by `factor * base^i`.
+fn scale_by_powers<T: FftScalar>(values: &mut [T], factor: &T, base: &T) {
+    let mut power = factor.clone();
+    for value in values.iter_mut() {
+        *value = value.clone() * power.clone();
+        power = power * base.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute_roots_of_unity;
+
+    fn evaluate_coefficients(coefficients: &[Scalar], x: Scalar) -> Scalar {
+        coefficients
+            .iter()
+            .rev()
+            .fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient)
+    }
+
+    #[test]
+    fn test_bit_reversal() {
+        assert_eq!(reverse_bits(1, 8), 4);
+        assert_eq!(reverse_bits(6, 8), 3);
+        assert_eq!(reverse_bits(0, 1), 0);
+        let mut values = [0, 1, 2, 3, 4, 5, 6, 7];
+        bit_reverse_in_place(&mut values);
+        assert_eq!(values, [0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn test_fft() {
+        let roots_of_unity = compute_roots_of_unity(16).unwrap();
+        let coefficients: Vec<Scalar> = (0..8u64).map(|i| Scalar::from(i * i + 3)).collect();
+        let shift = Scalar::from(7u64);
+
+        let mut evaluations = coefficients.clone();
+        fft(&mut evaluations, &roots_of_unity).unwrap();
+        let mut coset_evaluations = coefficients.clone();
+        coset_fft(&mut coset_evaluations, &shift, &roots_of_unity).unwrap();
+        for k in 0..8 {
+            // The primitive 8th root of unity is w_16^2.
+            let root = roots_of_unity[reverse_bits(2 * k, 16)];
+            assert_eq!(evaluations[k], evaluate_coefficients(&coefficients, root));
+            assert_eq!(
+                coset_evaluations[k],
+                evaluate_coefficients(&coefficients, shift * root)
+            );
+        }
+
+        ifft(&mut evaluations, &roots_of_unity).unwrap();
+        assert_eq!(evaluations, coefficients);
+        coset_ifft(&mut coset_evaluations, &shift, &roots_of_unity).unwrap();
+        assert_eq!(coset_evaluations, coefficients);
+
+        // The OpenVM scalar gives the same result.
+        let mut openvm_values: Vec<openvm_pairing::bls12_381::Scalar> =
+            coefficients.iter().map(FftScalar::from_scalar).collect();
+        fft(&mut openvm_values, &roots_of_unity).unwrap();
+        let mut expected = coefficients.clone();
+        fft(&mut expected, &roots_of_unity).unwrap();
+        assert_eq!(
+            openvm_values,
+            expected
+                .iter()
+                .map(FftScalar::from_scalar)
+                .collect::<Vec<openvm_pairing::bls12_381::Scalar>>()
+        );
+
+        assert!(fft(&mut [Scalar::zero(); 3], &roots_of_unity).is_err());
+        assert!(fft(&mut [Scalar::zero(); 32], &roots_of_unity).is_err());
+        assert!(coset_ifft(&mut evaluations, &Scalar::zero(), &roots_of_unity).is_err());
+    }
+}
@@ -0,0 +1,835 @@
+//! A deterministic generator of test vectors in the consensus-spec YAML format.
+//!
+//! [`VectorGenerator`] commits and proves with the G1 Lagrange points of a trusted setup, so that
+//! it works with the embedded setups as well as with an
+//! [`InsecureTrustedSetup`](crate::test_utils::InsecureTrustedSetup). Its randomness comes from a
+//! [`SeededRng`], so a seed always gives the same cases. Each case records the output it was
+//! constructed to have, not what the verifier returns.
+
+use alloc::{format, string::String, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use bls12_381::{G1Affine, G1Projective, Scalar};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    backend::KzgScalar,
+    codec::{decode_hex, encode_hex},
+    kzg_proof::compute_challenge,
+    test_utils::{commit_in_lagrange_form, prove_in_lagrange_form},
+    Blob, Bytes48, KzgError, KzgPreset, KzgSettings, TrustedSetupError, MODULUS,
+};
+
+/// A deterministic stream of random bytes: SHA-256 of the seed and a counter.
+///
+/// **WARNING:** only for generating test data.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    seed: u64,
+    counter: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(32) {
+            let mut hasher = Sha256::new();
+            hasher.update(self.seed.to_le_bytes());
+            hasher.update(self.counter.to_le_bytes());
+            self.counter += 1;
+            chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns a uniformly random scalar.
+    pub fn scalar(&mut self) -> Scalar {
+        let mut wide = [0u8; 64];
+        self.fill_bytes(&mut wide);
+        Scalar::from_bytes_wide(&wide)
+    }
+}
+
+/// A test case in the layout of the consensus-spec tests: `<format>/<name>/data.yaml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    /// The test format, such as `verify_kzg_proof`.
+    pub format: &'static str,
+    /// `<format>_case_<description>_<hash>`, where the hash is of `data`.
+    pub name: String,
+    /// The contents of `data.yaml`.
+    pub data: String,
+}
+
+impl TestCase {
+    /// A `verify_kzg_proof` case. The inputs may have any length, to test their rejection.
+    pub fn verify_kzg_proof(
+        description: &str,
+        commitment: &[u8],
+        z: &[u8],
+        y: &[u8],
+        proof: &[u8],
+        output: Option<bool>,
+    ) -> Self {
+        Self::new(
+            "verify_kzg_proof",
+            description,
+            &[
+                ("commitment", hex_value(commitment)),
+                ("z", hex_value(z)),
+                ("y", hex_value(y)),
+                ("proof", hex_value(proof)),
+            ],
+            output,
+        )
+    }
+
+    /// A `verify_blob_kzg_proof` case.
+    pub fn verify_blob_kzg_proof(
+        description: &str,
+        blob: &[u8],
+        commitment: &[u8],
+        proof: &[u8],
+        output: Option<bool>,
+    ) -> Self {
+        Self::new(
+            "verify_blob_kzg_proof",
+            description,
+            &[
+                ("blob", hex_value(blob)),
+                ("commitment", hex_value(commitment)),
+                ("proof", hex_value(proof)),
+            ],
+            output,
+        )
+    }
+
+    /// A `verify_blob_kzg_proof_batch` case. The lists may have different lengths.
+    pub fn verify_blob_kzg_proof_batch(
+        description: &str,
+        blobs: &[Vec<u8>],
+        commitments: &[Vec<u8>],
+        proofs: &[Vec<u8>],
+        output: Option<bool>,
+    ) -> Self {
+        Self::new(
+            "verify_blob_kzg_proof_batch",
+            description,
+            &[
+                ("blobs", hex_list(blobs)),
+                ("commitments", hex_list(commitments)),
+                ("proofs", hex_list(proofs)),
+            ],
+            output,
+        )
+    }
+
+    fn new(
+        format: &'static str,
+        description: &str,
+        input: &[(&str, String)],
+        output: Option<bool>,
+    ) -> Self {
+        let mut data = String::from("input:\n");
+        for (key, value) in input {
+            data += &format!("  {key}: {value}\n");
+        }
+        let output = match output {
+            Some(true) => "true",
+            Some(false) => "false",
+            None => "null",
+        };
+        data += &format!("output: {output}\n");
+        // The case names have the hash without the `0x` prefix, as upstream.
+        let hash = encode_hex(&Sha256::digest(data.as_bytes())[..8]);
+        let name = format!("{format}_case_{description}_{}", &hash[2..]);
+        Self { format, name, data }
+    }
+
+    /// Writes `<root>/<format>/<name>/data.yaml`.
+    #[cfg(feature = "std")]
+    pub fn write(&self, root: &std::path::Path) -> std::io::Result<()> {
+        let dir = root.join(self.format).join(&self.name);
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("data.yaml"), &self.data)
+    }
+}
+
+fn hex_value(bytes: &[u8]) -> String {
+    format!("'{}'", encode_hex(bytes))
+}
+
+fn hex_list(items: &[Vec<u8>]) -> String {
+    let values: Vec<String> = items.iter().map(|item| hex_value(item)).collect();
+    format!("[{}]", values.join(", "))
+}
+
+/// A polynomial in evaluation form with its blob, commitment and encodings.
+struct Sample<P: KzgPreset> {
+    evaluations: Vec<Scalar>,
+    blob: Blob<P>,
+    commitment: Vec<u8>,
+}
+
+/// Generates valid and invalid cases for the verification functions of the preset `P`.
+pub struct VectorGenerator<P: KzgPreset> {
+    settings: KzgSettings,
+    rng: SeededRng,
+    _preset: PhantomData<P>,
+}
+
+impl<P: KzgPreset> VectorGenerator<P> {
+    /// A generator using the embedded trusted setup of `P`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            settings: P::kzg_settings(),
+            rng: SeededRng::new(seed),
+            _preset: PhantomData,
+        }
+    }
+
+    /// A generator using another trusted setup for the domain of `P`, such as that of an
+    /// [`InsecureTrustedSetup`](crate::test_utils::InsecureTrustedSetup).
+    pub fn with_settings(settings: KzgSettings, seed: u64) -> Result<Self, KzgError> {
+        if settings.roots_of_unity.len() != P::FIELD_ELEMENTS_PER_BLOB {
+            return Err(TrustedSetupError::RootsOfUnityCount {
+                expected: P::FIELD_ELEMENTS_PER_BLOB,
+                actual: settings.roots_of_unity.len(),
+            }
+            .into());
+        }
+        if settings.g1_points.len() != P::FIELD_ELEMENTS_PER_BLOB {
+            return Err(TrustedSetupError::G1PointCount {
+                expected: P::FIELD_ELEMENTS_PER_BLOB,
+                actual: settings.g1_points.len(),
+            }
+            .into());
+        }
+        Ok(Self {
+            settings,
+            rng: SeededRng::new(seed),
+            _preset: PhantomData,
+        })
+    }
+
+    pub fn kzg_settings(&self) -> &KzgSettings {
+        &self.settings
+    }
+
+    /// Generates the cases of every format, with `count` rounds of random cases each.
+    pub fn generate(&mut self, count: usize) -> Result<Vec<TestCase>, KzgError> {
+        let mut cases = self.verify_kzg_proof_cases(count)?;
+        cases.extend(self.verify_blob_kzg_proof_cases(count)?);
+        cases.extend(self.verify_blob_kzg_proof_batch_cases(count)?);
+        Ok(cases)
+    }
+
+    /// Correct proofs, off-by-one `y`, proofs of another point, identity points, maximal and
+    /// non-canonical scalars, and invalid points.
+    pub fn verify_kzg_proof_cases(&mut self, count: usize) -> Result<Vec<TestCase>, KzgError> {
+        let mut cases = Vec::new();
+        for _ in 0..count {
+            let sample = self.random_sample()?;
+            let z = self.rng.scalar();
+            let (proof, y) = self.prove(&sample.evaluations, &z)?;
+            cases.push(point_case(
+                "correct_proof",
+                &sample,
+                &z,
+                &y,
+                &proof,
+                Some(true),
+            ));
+
+            let y_plus_one = y + Scalar::one();
+            cases.push(point_case(
+                "incorrect_proof_off_by_one_y",
+                &sample,
+                &z,
+                &y_plus_one,
+                &proof,
+                Some(false),
+            ));
+
+            // A valid proof, but of another point.
+            let other_z = self.rng.scalar();
+            let (other_proof, _) = self.prove(&sample.evaluations, &other_z)?;
+            cases.push(point_case(
+                "incorrect_proof_swapped",
+                &sample,
+                &z,
+                &y,
+                &other_proof,
+                Some(false),
+            ));
+            cases.push(point_case(
+                "incorrect_proof_point_at_infinity",
+                &sample,
+                &z,
+                &y,
+                &identity(),
+                Some(false),
+            ));
+
+            let index = (self.rng.next_u64() % P::FIELD_ELEMENTS_PER_BLOB as u64) as usize;
+            let root = self.settings.roots_of_unity[index];
+            let (proof, y) = self.prove(&sample.evaluations, &root)?;
+            cases.push(point_case(
+                "correct_proof_at_root_of_unity",
+                &sample,
+                &root,
+                &y,
+                &proof,
+                Some(true),
+            ));
+        }
+
+        let z = self.rng.scalar();
+        for (description, value) in [
+            ("zero_poly", Scalar::zero()),
+            ("twos_poly", Scalar::from(2u64)),
+        ] {
+            // A constant polynomial has an identity proof.
+            let sample = self.sample(vec![value; P::FIELD_ELEMENTS_PER_BLOB])?;
+            cases.push(point_case(
+                &format!("correct_proof_point_at_infinity_for_{description}"),
+                &sample,
+                &z,
+                &value,
+                &identity(),
+                Some(true),
+            ));
+        }
+
+        let maximal = -Scalar::one();
+        let sample = self.random_sample()?;
+        let (proof, y) = self.prove(&sample.evaluations, &maximal)?;
+        cases.push(point_case(
+            "correct_proof_maximal_z",
+            &sample,
+            &maximal,
+            &y,
+            &proof,
+            Some(true),
+        ));
+        let constant = self.sample(vec![maximal; P::FIELD_ELEMENTS_PER_BLOB])?;
+        cases.push(point_case(
+            "correct_proof_maximal_y",
+            &constant,
+            &z,
+            &maximal,
+            &identity(),
+            Some(true),
+        ));
+
+        let (proof, y) = self.prove(&sample.evaluations, &z)?;
+        let (z_bytes, y_bytes) = (z.to_be_bytes32(), y.to_be_bytes32());
+        for (description, scalar) in non_canonical_scalars() {
+            cases.push(TestCase::verify_kzg_proof(
+                &format!("invalid_z_{description}"),
+                &sample.commitment,
+                &scalar,
+                &y_bytes,
+                &proof,
+                None,
+            ));
+            cases.push(TestCase::verify_kzg_proof(
+                &format!("invalid_y_{description}"),
+                &sample.commitment,
+                &z_bytes,
+                &scalar,
+                &proof,
+                None,
+            ));
+        }
+        for (description, point) in self.invalid_points(&sample.commitment) {
+            cases.push(TestCase::verify_kzg_proof(
+                &format!("invalid_commitment_{description}"),
+                &point,
+                &z_bytes,
+                &y_bytes,
+                &proof,
+                None,
+            ));
+            cases.push(TestCase::verify_kzg_proof(
+                &format!("invalid_proof_{description}"),
+                &sample.commitment,
+                &z_bytes,
+                &y_bytes,
+                &point,
+                None,
+            ));
+        }
+        Ok(cases)
+    }
+
+    /// Correct proofs, proofs of another blob, identity points, maximal and non-canonical field
+    /// elements, and invalid points.
+    pub fn verify_blob_kzg_proof_cases(&mut self, count: usize) -> Result<Vec<TestCase>, KzgError> {
+        let mut cases = Vec::new();
+        for _ in 0..count {
+            let sample = self.random_sample()?;
+            let proof = self.prove_blob(&sample)?;
+            cases.push(blob_case("correct_proof", &sample, &proof, Some(true)));
+
+            let other = self.random_sample()?;
+            let other_proof = self.prove_blob(&other)?;
+            cases.push(blob_case(
+                "incorrect_proof_swapped",
+                &sample,
+                &other_proof,
+                Some(false),
+            ));
+            cases.push(blob_case(
+                "incorrect_proof_point_at_infinity",
+                &sample,
+                &identity(),
+                Some(false),
+            ));
+        }
+
+        let zero = self.sample(vec![Scalar::zero(); P::FIELD_ELEMENTS_PER_BLOB])?;
+        cases.push(blob_case(
+            "correct_proof_point_at_infinity_for_zero_poly",
+            &zero,
+            &identity(),
+            Some(true),
+        ));
+        let maximal = self.sample(vec![-Scalar::one(); P::FIELD_ELEMENTS_PER_BLOB])?;
+        cases.push(blob_case(
+            "correct_proof_maximal_field_elements",
+            &maximal,
+            &identity(),
+            Some(true),
+        ));
+
+        let sample = self.random_sample()?;
+        let proof = self.prove_blob(&sample)?;
+        for (description, scalar) in non_canonical_scalars() {
+            let mut blob = sample.blob.as_slice().to_vec();
+            let index = (self.rng.next_u64() % P::FIELD_ELEMENTS_PER_BLOB as u64) as usize;
+            blob[index * 32..(index + 1) * 32].copy_from_slice(&scalar);
+            cases.push(TestCase::verify_blob_kzg_proof(
+                &format!("invalid_blob_{description}"),
+                &blob,
+                &sample.commitment,
+                &proof,
+                None,
+            ));
+        }
+        for (description, point) in self.invalid_points(&sample.commitment) {
+            cases.push(TestCase::verify_blob_kzg_proof(
+                &format!("invalid_commitment_{description}"),
+                sample.blob.as_slice(),
+                &point,
+                &proof,
+                None,
+            ));
+            cases.push(TestCase::verify_blob_kzg_proof(
+                &format!("invalid_proof_{description}"),
+                sample.blob.as_slice(),
+                &sample.commitment,
+                &point,
+                None,
+            ));
+        }
+        Ok(cases)
+    }
+
+    /// Correct batches of up to 3 blobs, including the empty batch, swapped proofs, proofs off by
+    /// the generator, identity proofs, invalid inputs and lists of different lengths.
+    pub fn verify_blob_kzg_proof_batch_cases(
+        &mut self,
+        count: usize,
+    ) -> Result<Vec<TestCase>, KzgError> {
+        let mut cases = Vec::new();
+        for round in 0..count {
+            let (blobs, commitments, mut proofs) = self.random_batch(round % 4)?;
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                "correct_proof",
+                &blobs,
+                &commitments,
+                &proofs,
+                Some(true),
+            ));
+            if proofs.len() < 2 {
+                continue;
+            }
+
+            let mut swapped = proofs.clone();
+            swapped.swap(0, 1);
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                "incorrect_proof_swapped",
+                &blobs,
+                &commitments,
+                &swapped,
+                Some(false),
+            ));
+            let proof: [u8; 48] = proofs[1].as_slice().try_into().expect("a compressed point");
+            let proof = G1Affine::from_compressed(&proof).expect("a valid proof");
+            let proof = G1Projective::from(proof) + G1Affine::generator();
+            proofs[1] = G1Affine::from(proof).to_compressed().to_vec();
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                "incorrect_proof_add_one",
+                &blobs,
+                &commitments,
+                &proofs,
+                Some(false),
+            ));
+            proofs[1] = identity();
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                "incorrect_proof_point_at_infinity",
+                &blobs,
+                &commitments,
+                &proofs,
+                Some(false),
+            ));
+        }
+
+        let (blobs, commitments, proofs) = self.random_batch(2)?;
+        for (description, scalar) in non_canonical_scalars() {
+            let mut invalid_blobs = blobs.clone();
+            invalid_blobs[1][..32].copy_from_slice(&scalar);
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                &format!("invalid_blob_{description}"),
+                &invalid_blobs,
+                &commitments,
+                &proofs,
+                None,
+            ));
+        }
+        for (description, point) in self.invalid_points(&commitments[0]) {
+            let mut invalid = commitments.clone();
+            invalid[1] = point.clone();
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                &format!("invalid_commitment_{description}"),
+                &blobs,
+                &invalid,
+                &proofs,
+                None,
+            ));
+            let mut invalid = proofs.clone();
+            invalid[1] = point;
+            cases.push(TestCase::verify_blob_kzg_proof_batch(
+                &format!("invalid_proof_{description}"),
+                &blobs,
+                &commitments,
+                &invalid,
+                None,
+            ));
+        }
+        cases.push(TestCase::verify_blob_kzg_proof_batch(
+            "blob_length_different",
+            &blobs[..1],
+            &commitments,
+            &proofs,
+            None,
+        ));
+        cases.push(TestCase::verify_blob_kzg_proof_batch(
+            "commitment_length_different",
+            &blobs,
+            &commitments[..1],
+            &proofs,
+            None,
+        ));
+        cases.push(TestCase::verify_blob_kzg_proof_batch(
+            "proof_length_different",
+            &blobs,
+            &commitments,
+            &proofs[..1],
+            None,
+        ));
+        Ok(cases)
+    }
+
+    fn random_sample(&mut self) -> Result<Sample<P>, KzgError> {
+        let evaluations = (0..P::FIELD_ELEMENTS_PER_BLOB)
+            .map(|_| self.rng.scalar())
+            .collect();
+        self.sample(evaluations)
+    }
+
+    fn sample(&self, evaluations: Vec<Scalar>) -> Result<Sample<P>, KzgError> {
+        let bytes: Vec<u8> = evaluations
+            .iter()
+            .flat_map(KzgScalar::to_be_bytes32)
+            .collect();
+        let blob = Blob::from_slice(&bytes)?;
+        let commitment = commit_in_lagrange_form(&evaluations, &self.settings.g1_points)?
+            .to_compressed()
+            .to_vec();
+        Ok(Sample {
+            evaluations,
+            blob,
+            commitment,
+        })
+    }
+
+    fn random_batch(
+        &mut self,
+        size: usize,
+    ) -> Result<(Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<Vec<u8>>), KzgError> {
+        let mut blobs = Vec::with_capacity(size);
+        let mut commitments = Vec::with_capacity(size);
+        let mut proofs = Vec::with_capacity(size);
+        for _ in 0..size {
+            let sample = self.random_sample()?;
+            proofs.push(self.prove_blob(&sample)?);
+            blobs.push(sample.blob.as_slice().to_vec());
+            commitments.push(sample.commitment);
+        }
+        Ok((blobs, commitments, proofs))
+    }
+
+    /// Returns the proof of the evaluation at `z` and the evaluation.
+    fn prove(&self, evaluations: &[Scalar], z: &Scalar) -> Result<(Vec<u8>, Scalar), KzgError> {
+        let (proof, y) = prove_in_lagrange_form(evaluations.to_vec(), z, &self.settings)?;
+        Ok((proof.to_compressed().to_vec(), y))
+    }
+
+    /// Returns the proof of the blob at its Fiat-Shamir challenge.
+    fn prove_blob(&self, sample: &Sample<P>) -> Result<Vec<u8>, KzgError> {
+        let commitment = Bytes48::from_slice(&sample.commitment)?;
+        let z = compute_challenge(&sample.blob, &commitment);
+        Ok(self.prove(&sample.evaluations, &z)?.0)
+    }
+
+    /// Encodings that are not a point of the G1 subgroup, derived from the valid `point`.
+    fn invalid_points(&mut self, point: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+        // A compressed x with no point on the curve.
+        let not_on_curve = loop {
+            let mut bytes = [0u8; 48];
+            self.rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] & 0x1f) | 0x80;
+            if bool::from(G1Affine::from_compressed_unchecked(&bytes).is_none()) {
+                break bytes.to_vec();
+            }
+        };
+        // (4, y) is on the curve, but not in the subgroup.
+        let mut not_in_subgroup = vec![0u8; 48];
+        not_in_subgroup[0] = 0x80;
+        not_in_subgroup[47] = 4;
+        // The base field modulus as x.
+        let mut x_modulus = decode_hex("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab").unwrap();
+        x_modulus[0] |= 0x80;
+        let mut identity_with_sign = identity();
+        identity_with_sign[0] |= 0x20;
+        let mut not_compressed = point.to_vec();
+        not_compressed[0] &= 0x7f;
+        let mut too_long = point.to_vec();
+        too_long.push(0);
+
+        vec![
+            ("not_on_curve", not_on_curve),
+            ("not_in_g1", not_in_subgroup),
+            ("x_equal_to_modulus", x_modulus),
+            ("infinity_with_sign", identity_with_sign),
+            ("not_compressed", not_compressed),
+            ("too_short", point[..47].to_vec()),
+            ("too_long", too_long),
+        ]
+    }
+}
+
+fn point_case<P: KzgPreset>(
+    description: &str,
+    sample: &Sample<P>,
+    z: &Scalar,
+    y: &Scalar,
+    proof: &[u8],
+    output: Option<bool>,
+) -> TestCase {
+    TestCase::verify_kzg_proof(
+        description,
+        &sample.commitment,
+        &z.to_be_bytes32(),
+        &y.to_be_bytes32(),
+        proof,
+        output,
+    )
+}
+
+fn blob_case<P: KzgPreset>(
+    description: &str,
+    sample: &Sample<P>,
+    proof: &[u8],
+    output: Option<bool>,
+) -> TestCase {
+    TestCase::verify_blob_kzg_proof(
+        description,
+        sample.blob.as_slice(),
+        &sample.commitment,
+        proof,
+        output,
+    )
+}
+
+fn identity() -> Vec<u8> {
+    G1Affine::identity().to_compressed().to_vec()
+}
+
+/// Big-endian 32-byte values that are not canonical scalars.
+fn non_canonical_scalars() -> [(&'static str, [u8; 32]); 2] {
+    let mut modulus = [0u8; 32];
+    for (chunk, limb) in modulus.chunks_mut(8).zip(MODULUS.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    [("equal_to_modulus", modulus), ("max", [0xff; 32])]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        differential::{verify_blob_kzg_proof, verify_blob_kzg_proof_batch, verify_kzg_proof},
+        test_utils::{BlobBatchInput, BlobInput, Input, InsecureTrustedSetup, Test},
+        test_vectors::{TestFormat, VerifyBlobKzgProof, VerifyBlobKzgProofBatch, VerifyKzgProof},
+        Minimal, VerificationOutcome,
+    };
+    use serde::de::IgnoredAny;
+    use serde_yaml::Value;
+
+    /// Returns the output of both backends on the case, in the terms of the spec tests.
+    fn verify(case: &TestCase, kzg_settings: &KzgSettings) -> Option<bool> {
+        let verdicts = match case.format {
+            VerifyKzgProof::FORMAT => {
+                let test: Test<Input> = serde_yaml::from_str(&case.data).unwrap();
+                verify_kzg_proof(
+                    &test.input.get_commitment().ok()?,
+                    &test.input.get_z().ok()?,
+                    &test.input.get_y().ok()?,
+                    &test.input.get_proof().ok()?,
+                    kzg_settings,
+                )
+            }
+            VerifyBlobKzgProof::FORMAT => {
+                let test: Test<BlobInput> = serde_yaml::from_str(&case.data).unwrap();
+                verify_blob_kzg_proof(
+                    &test.input.get_blob_for::<Minimal>().ok()?,
+                    &test.input.get_commitment().ok()?,
+                    &test.input.get_proof().ok()?,
+                    kzg_settings,
+                )
+            }
+            VerifyBlobKzgProofBatch::FORMAT => {
+                let test: Test<BlobBatchInput> = serde_yaml::from_str(&case.data).unwrap();
+                verify_blob_kzg_proof_batch(
+                    &test.input.get_blobs_for::<Minimal>().ok()?,
+                    &test.input.get_commitments().ok()?,
+                    &test.input.get_proofs().ok()?,
+                    kzg_settings,
+                )
+            }
+            format => panic!("unexpected format {format}"),
+        };
+        VerificationOutcome::spec_output(&verdicts.expect_agreement())
+    }
+
+    fn assert_outputs(cases: &[TestCase], kzg_settings: &KzgSettings) {
+        for case in cases {
+            let test: Test<IgnoredAny> = serde_yaml::from_str(&case.data).unwrap();
+            assert_eq!(
+                verify(case, kzg_settings),
+                test.get_output(),
+                "{}",
+                case.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_generation_is_deterministic() {
+        let cases = VectorGenerator::<Minimal>::new(7).generate(2).unwrap();
+        assert_eq!(
+            cases,
+            VectorGenerator::<Minimal>::new(7).generate(2).unwrap()
+        );
+        assert_ne!(
+            cases,
+            VectorGenerator::<Minimal>::new(8).generate(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cases_have_their_output() {
+        let mut generator = VectorGenerator::<Minimal>::new(1);
+        let cases = generator.generate(4).unwrap();
+        for format in [
+            "verify_kzg_proof",
+            "verify_blob_kzg_proof",
+            "verify_blob_kzg_proof_batch",
+        ] {
+            for output in ["true", "false", "null"] {
+                let suffix = format!("output: {output}\n");
+                assert!(
+                    cases
+                        .iter()
+                        .any(|case| case.format == format && case.data.ends_with(&suffix)),
+                    "no {format} case with {suffix}"
+                );
+            }
+        }
+        assert_outputs(&cases, generator.kzg_settings());
+    }
+
+    #[test]
+    fn test_known_secret_setup() {
+        let kzg_settings = InsecureTrustedSetup::generate(Scalar::from(42u64), 4)
+            .unwrap()
+            .to_kzg_settings();
+        let mut generator = VectorGenerator::<Minimal>::with_settings(kzg_settings, 3).unwrap();
+        let cases = generator.generate(2).unwrap();
+        assert_outputs(&cases, generator.kzg_settings());
+        // The proofs are for the known secret, not the embedded one.
+        let embedded = Minimal::kzg_settings();
+        assert!(cases
+            .iter()
+            .filter(|case| case.name.contains("_case_correct_proof_"))
+            .any(|case| verify(case, &embedded) == Some(false)));
+    }
+
+    #[test]
+    fn test_spec_schema() {
+        let cases = VectorGenerator::<Minimal>::new(5)
+            .verify_kzg_proof_cases(1)
+            .unwrap();
+        for case in cases {
+            assert!(case.name.starts_with("verify_kzg_proof_case_"));
+            let test: Test<Input> = serde_yaml::from_str(&case.data).unwrap();
+            let data: Value = serde_yaml::from_str(&case.data).unwrap();
+            assert_eq!(test.get_output(), data["output"].as_bool());
+        }
+    }
+
+    #[test]
+    fn test_settings_must_match_preset() {
+        assert_eq!(
+            VectorGenerator::<Minimal>::with_settings(crate::Mainnet::kzg_settings(), 0).err(),
+            Some(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::RootsOfUnityCount {
+                    expected: 4,
+                    actual: 4096
+                }
+            ))
+        );
+        let mut kzg_settings = Minimal::kzg_settings();
+        kzg_settings.g1_points.to_mut().pop();
+        assert_eq!(
+            VectorGenerator::<Minimal>::with_settings(kzg_settings, 0).err(),
+            Some(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::G1PointCount {
+                    expected: 4,
+                    actual: 3
+                }
+            ))
+        );
+    }
+}
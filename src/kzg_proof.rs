@@ -1,147 +1,370 @@
+use crate::backend::{batch_lincombs, DefaultBackend, KzgBackend, KzgScalar};
 use crate::dtypes::*;
-use crate::enums::KzgError;
-use crate::types::KzgSettings;
+use crate::enums::{InputError, KzgError, TrustedSetupError, VerificationOutcome};
+use crate::fft::FftScalar;
+use crate::polynomial::Polynomial;
+use crate::preset::KzgPreset;
+use crate::types::VerifierSetup;
 
-use alloc::{string::ToString, vec::Vec};
-#[cfg(not(feature = "use-intrinsics"))]
-use bls12_381::{multi_miller_loop, G2Prepared, Gt};
+use alloc::vec::Vec;
 use bls12_381::{G1Affine, G2Affine, Scalar};
 #[cfg(target_os = "zkvm")]
 use core::cmp::Ordering;
+use core::marker::PhantomData;
+#[cfg(target_os = "zkvm")]
+use hex_literal::hex;
 use {
-    hex_literal::hex,
-    openvm_algebra_guest::field::FieldExtension,
     openvm_algebra_guest::IntMod,
-    openvm_ecc_guest::{
-        weierstrass::{CachedMulTable, IntrinsicCurve, WeierstrassPoint},
-        AffinePoint, CyclicGroup, Group,
-    },
-    openvm_pairing::bls12_381::{
-        Bls12_381 as Bls12_381_G1, Fp, Fp2, G1Affine as Bls12_381G1Affine,
-        G2Affine as Bls12_381G2Affine, Scalar as Bls12_381Scalar,
-    },
+    openvm_ecc_guest::{weierstrass::WeierstrassPoint, AffinePoint, Group},
+    openvm_pairing::bls12_381::{Fp, Fp2, G1Affine as Bls12_381G1Affine},
+    sha2::{Digest, Sha256},
 };
 
-const G2_AFFINE_GENERATOR: Bls12_381G2Affine = Bls12_381G2Affine::new(
-    Fp2::new(
-        Fp::from_const_bytes(hex!("B8BD21C1C85680D4EFBB05A82603AC0B77D1E37A640B51B4023B40FAD47AE4C65110C52D27050826910A8FF0B2A24A02")),
-        Fp::from_const_bytes(hex!("7E2B045D057DACE5575D941312F14C3349507FDCBB61DAB51AB62099D0D06B59654F2788A0D3AC7D609F7152602BE013"))
-    ),
-    Fp2::new(
-        Fp::from_const_bytes(hex!("0128B808865493E189A2AC3BCCC93A922CD16051699A426DA7D3BD8CAA9BFDAD1A352EDAC6CDC98C116E7D7227D5E50C")),
-        Fp::from_const_bytes(hex!("BE795FF05F07A9AAA11DEC5C270D373FAB992E57AB927426AF63A7857E283ECB998BC22BB0D2AC32CC34A72EA0C40606"))
-    )
-);
+/// Evaluates to the decoded value, or returns the outcome of an input that fails to decode.
+macro_rules! decode {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(e) => return VerificationOutcome::from_error(&e).ok_or(e),
+        }
+    };
+}
 
-pub struct KzgProof {}
+/// The KZG verification functions, running on the arithmetic of the backend `K`.
+///
+/// [`KzgProof`] verifies with the [`DefaultBackend`]; name a backend to pick one at the call
+/// site, as in `KzgVerifier::<NativeBackend>::verify_kzg_proof`.
+///
+/// [`NativeBackend`]: crate::backend::NativeBackend
+pub struct KzgVerifier<K> {
+    _backend: PhantomData<K>,
+}
 
-impl KzgProof {
+/// The KZG verification functions on the [`DefaultBackend`].
+pub type KzgProof = KzgVerifier<DefaultBackend>;
+
+impl<K: KzgBackend> KzgVerifier<K> {
     /// This function asserts that the KZG proof is valid. It will panic if the proof is not valid.
     ///
-    /// **WARNING:** a dishonest host of the VM may cause this function to panic even on valid inputs,
-    /// so this function cannot be used to prove that the KZG proof is definitely invalid.
+    /// **WARNING:** when using intrinsics, a dishonest host of the VM may cause this function to
+    /// panic even on valid inputs, so this function cannot be used to prove that the KZG proof is
+    /// definitely invalid.
     ///
     /// Therefore this function should only be used in cases where successful guest program execution requires
     /// the KZG proof to be valid.
-    #[cfg(feature = "use-intrinsics")]
+    ///
+    /// Inputs that fail to decode give the matching [`VerificationOutcome`] rather than an error,
+    /// so that a malformed input can be told apart from a wrong proof. The verify functions only
+    /// return an error when they are misused, for example with a trusted setup for another domain.
     pub fn verify_kzg_proof(
         commitment_bytes: &Bytes48,
         z_bytes: &Bytes32,
         y_bytes: &Bytes32,
         proof_bytes: &Bytes48,
-        kzg_settings: &KzgSettings,
-    ) -> Result<bool, KzgError> {
-        // Check that the scalar is valid
-        let z = Bls12_381Scalar::from_be_bytes(z_bytes.as_slice())
-            .ok_or_else(|| KzgError::BadArgs("Scalar z is not reduced".to_string()))?;
-        let y = Bls12_381Scalar::from_be_bytes(y_bytes.as_slice())
-            .ok_or_else(|| KzgError::BadArgs("Scalar y is not reduced".to_string()))?;
-
-        let commitment = safe_g1_affine_from_bytes(commitment_bytes)?;
-        let proof = safe_g1_affine_from_bytes(proof_bytes)?;
-
-        let openvm_kzg_g2_point = to_openvm_g2_affine(kzg_settings.g2_points[1]);
-
-        // Used for CachedMulTable implementation of msm for Bls12_381_G2.
-        #[allow(non_camel_case_types)]
-        struct Bls12_381_G2;
-        impl IntrinsicCurve for Bls12_381_G2 {
-            type Scalar = Bls12_381Scalar; // order of the generator is prime
-            type Point = Bls12_381G2Affine;
-
-            fn msm(coeffs: &[Self::Scalar], bases: &[Self::Point]) -> Self::Point {
-                openvm_ecc_guest::msm(coeffs, bases)
-            }
-        }
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_kzg_proof_with(
+            commitment_bytes,
+            z_bytes,
+            y_bytes,
+            proof_bytes,
+            kzg_settings,
+            K::decode_g1,
+        )
+    }
 
-        // We use the fact that g2_affine_generator has prime order.
-        let table = CachedMulTable::<Bls12_381_G2>::new_with_prime_order(&[G2_AFFINE_GENERATOR], 4);
-        let g2_z = table.windowed_mul(&[z]);
+    /// Verifies that `proof_bytes` opens the commitment to `blob` at the Fiat-Shamir challenge
+    /// derived from the blob and the commitment, as in the consensus-spec `verify_blob_kzg_proof`.
+    ///
+    /// `kzg_settings` must be a trusted setup for the domain of the preset `P`, including its roots
+    /// of unity.
+    ///
+    /// The same caveat as for [`Self::verify_kzg_proof`] applies when using intrinsics.
+    pub fn verify_blob_kzg_proof<B: AsBlobRef>(
+        blob: &B,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_blob_kzg_proof_with(
+            blob,
+            commitment_bytes,
+            proof_bytes,
+            kzg_settings,
+            K::decode_g1,
+        )
+    }
 
-        let x_minus_z = openvm_kzg_g2_point - g2_z;
+    /// Verifies a batch of blob KZG proofs at once, as in the consensus-spec
+    /// `verify_blob_kzg_proof_batch`. An empty batch is valid.
+    ///
+    /// The same caveat as for [`Self::verify_kzg_proof`] applies when using intrinsics.
+    pub fn verify_blob_kzg_proof_batch<B: AsBlobRef>(
+        blobs: &[B],
+        commitments_bytes: &[Bytes48],
+        proofs_bytes: &[Bytes48],
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_blob_kzg_proof_batch_with(
+            blobs,
+            commitments_bytes,
+            proofs_bytes,
+            kzg_settings,
+            K::decode_g1,
+        )
+    }
 
-        // We use the fact that Bls12_381G1Affine::GENERATOR has prime order.
-        let table = CachedMulTable::<Bls12_381_G1>::new_with_prime_order(
-            &[Bls12_381G1Affine::GENERATOR],
-            4,
-        );
-        let g1_y = table.windowed_mul(&[y]);
+    /// [`Self::verify_kzg_proof`] with typed arguments, so that a proof cannot be passed as the
+    /// commitment. `z` is the evaluation point and `y` the claimed value.
+    ///
+    /// The points were checked when the arguments were constructed, so they are not checked
+    /// again.
+    pub fn verify_kzg_proof_typed(
+        commitment: &KzgCommitment,
+        z: &FieldElement,
+        y: &FieldElement,
+        proof: &KzgProofBytes,
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_kzg_proof_with(
+            commitment.as_bytes(),
+            z.as_bytes(),
+            y.as_bytes(),
+            proof.as_bytes(),
+            kzg_settings,
+            decode_validated_g1::<K>,
+        )
+    }
 
-        let p_minus_y = commitment - g1_y;
+    /// [`Self::verify_blob_kzg_proof`] with typed arguments.
+    pub fn verify_blob_kzg_proof_typed<B: AsBlobRef>(
+        blob: &B,
+        commitment: &KzgCommitment,
+        proof: &KzgProofBytes,
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_blob_kzg_proof_with(
+            blob,
+            commitment.as_bytes(),
+            proof.as_bytes(),
+            kzg_settings,
+            decode_validated_g1::<K>,
+        )
+    }
 
-        let success = pairings_verify(p_minus_y, G2_AFFINE_GENERATOR.clone(), proof, x_minus_z);
-        Ok(success)
+    /// [`Self::verify_blob_kzg_proof_batch`] with typed arguments.
+    pub fn verify_blob_kzg_proof_batch_typed<B: AsBlobRef>(
+        blobs: &[B],
+        commitments: &[KzgCommitment],
+        proofs: &[KzgProofBytes],
+        kzg_settings: &impl VerifierSetup,
+    ) -> Result<VerificationOutcome, KzgError> {
+        Self::verify_blob_kzg_proof_batch_with(
+            blobs,
+            commitments,
+            proofs,
+            kzg_settings,
+            decode_validated_g1::<K>,
+        )
     }
 
-    /// Does not use intrinsics. Pure Rust implementation.
-    #[cfg(not(feature = "use-intrinsics"))]
-    pub fn verify_kzg_proof(
+    /// [`Self::verify_kzg_proof`], decoding the points with `decode_g1`.
+    fn verify_kzg_proof_with(
         commitment_bytes: &Bytes48,
         z_bytes: &Bytes32,
         y_bytes: &Bytes32,
         proof_bytes: &Bytes48,
-        kzg_settings: &KzgSettings,
-    ) -> Result<bool, KzgError> {
+        kzg_settings: &impl VerifierSetup,
+        decode_g1: impl Fn(&Bytes48) -> Result<K::G1, KzgError>,
+    ) -> Result<VerificationOutcome, KzgError> {
         // Check that the scalar is valid
-        let z = safe_scalar_affine_from_bytes(z_bytes)?;
-        let y = safe_scalar_affine_from_bytes(y_bytes)?;
-
-        let commitment = safe_g1_affine_from_bytes_native(commitment_bytes)?;
-        let proof = safe_g1_affine_from_bytes_native(proof_bytes)?;
-
-        let kzg_g2_point = kzg_settings.g2_points[1];
-        let g2_z = G2Affine::generator() * z;
-        let x_minus_z = G2Affine::from(kzg_g2_point - g2_z);
-        let g1_y = G1Affine::generator() * y;
-        let p_minus_y = G1Affine::from(commitment - g1_y);
-
-        let success = multi_miller_loop(&[
-            (&-p_minus_y, &G2Prepared::from(G2Affine::generator())),
-            (&proof, &G2Prepared::from(x_minus_z)),
-        ])
-        .final_exponentiation()
-            == Gt::identity();
-        Ok(success)
+        let z = decode!(K::Scalar::from_be_bytes32(z_bytes)
+            .ok_or(KzgError::BadArgs(InputError::UnreducedScalar("z"))));
+        let y = decode!(K::Scalar::from_be_bytes32(y_bytes)
+            .ok_or(KzgError::BadArgs(InputError::UnreducedScalar("y"))));
+
+        let commitment = decode!(decode_g1(commitment_bytes));
+        let proof = decode!(decode_g1(proof_bytes));
+
+        Ok(verify_kzg_proof_impl::<K>(commitment, z, y, proof, kzg_settings.g2_tau()).into())
+    }
+
+    /// [`Self::verify_blob_kzg_proof`], decoding the points with `decode_g1`.
+    fn verify_blob_kzg_proof_with<B: AsBlobRef>(
+        blob: &B,
+        commitment_bytes: &Bytes48,
+        proof_bytes: &Bytes48,
+        kzg_settings: &impl VerifierSetup,
+        decode_g1: impl Fn(&Bytes48) -> Result<K::G1, KzgError>,
+    ) -> Result<VerificationOutcome, KzgError> {
+        let blob = blob.as_blob_ref();
+        let roots_of_unity = check_domain::<B::Preset>(kzg_settings)?;
+
+        let commitment = decode!(decode_g1(commitment_bytes));
+        let proof = decode!(decode_g1(proof_bytes));
+
+        let polynomial = decode!(Polynomial::<K::Scalar>::from_blob(&blob));
+        let z = K::Scalar::from_scalar(&compute_challenge(&blob, commitment_bytes));
+        let y = polynomial.evaluate(&z, roots_of_unity)?;
+
+        Ok(verify_kzg_proof_impl::<K>(commitment, z, y, proof, kzg_settings.g2_tau()).into())
+    }
+
+    /// [`Self::verify_blob_kzg_proof_batch`], decoding the points with `decode_g1`.
+    fn verify_blob_kzg_proof_batch_with<B: AsBlobRef>(
+        blobs: &[B],
+        commitments_bytes: &[impl AsRef<Bytes48>],
+        proofs_bytes: &[impl AsRef<Bytes48>],
+        kzg_settings: &impl VerifierSetup,
+        decode_g1: impl Fn(&Bytes48) -> Result<K::G1, KzgError>,
+    ) -> Result<VerificationOutcome, KzgError> {
+        if blobs.len() != commitments_bytes.len() || blobs.len() != proofs_bytes.len() {
+            return Err(KzgError::BadArgs(InputError::BatchLengthMismatch {
+                blobs: blobs.len(),
+                commitments: commitments_bytes.len(),
+                proofs: proofs_bytes.len(),
+            }));
+        }
+        if blobs.is_empty() {
+            return Ok(VerificationOutcome::Valid);
+        }
+        if blobs.len() == 1 {
+            return Self::verify_blob_kzg_proof_with(
+                &blobs[0],
+                commitments_bytes[0].as_ref(),
+                proofs_bytes[0].as_ref(),
+                kzg_settings,
+                decode_g1,
+            );
+        }
+        let roots_of_unity = check_domain::<B::Preset>(kzg_settings)?;
+
+        let mut commitments = Vec::with_capacity(blobs.len());
+        let mut proofs = Vec::with_capacity(blobs.len());
+        let mut zs = Vec::with_capacity(blobs.len());
+        let mut ys = Vec::with_capacity(blobs.len());
+        for ((blob, commitment_bytes), proof_bytes) in
+            blobs.iter().zip(commitments_bytes).zip(proofs_bytes)
+        {
+            commitments.push(decode!(decode_g1(commitment_bytes.as_ref())));
+            proofs.push(decode!(decode_g1(proof_bytes.as_ref())));
+
+            let blob = blob.as_blob_ref();
+            let polynomial = decode!(Polynomial::<K::Scalar>::from_blob(&blob));
+            let z = K::Scalar::from_scalar(&compute_challenge(&blob, commitment_bytes.as_ref()));
+            ys.push(polynomial.evaluate(&z, roots_of_unity)?);
+            zs.push(z);
+        }
+
+        let r = K::Scalar::from_scalar(&compute_batch_challenge::<B::Preset, _>(
+            commitments_bytes,
+            &zs,
+            &ys,
+            proofs_bytes,
+        ));
+        Ok(verify_kzg_proof_batch_impl::<K>(
+            &commitments,
+            &zs,
+            &ys,
+            &proofs,
+            &r,
+            kzg_settings.g2_tau(),
+        )
+        .into())
     }
 }
 
-/// Verifies the pairing of two G1 and two G2 points are equivalent using the multi-miller loop.
-fn pairings_verify(
-    p0: Bls12_381G1Affine,
-    p1: Bls12_381G2Affine,
-    q0: Bls12_381G1Affine,
-    q1: Bls12_381G2Affine,
+/// Decodes a point of a typed argument, which was checked on construction.
+fn decode_validated_g1<K: KzgBackend>(bytes: &Bytes48) -> Result<K::G1, KzgError> {
+    Ok(K::decode_validated_g1(bytes))
+}
+
+fn verify_kzg_proof_impl<K: KzgBackend>(
+    commitment: K::G1,
+    z: K::Scalar,
+    y: K::Scalar,
+    proof: K::G1,
+    g2_tau: G2Affine,
 ) -> bool {
-    use openvm_pairing::{bls12_381::Bls12_381, PairingCheck};
+    let x_minus_z = K::g2_from_affine(&g2_tau) - K::g2_mul_generator(&z);
+    let p_minus_y = commitment - K::g1_mul_generator(&y);
+
+    K::pairings_verify(p_minus_y, K::g2_generator(), proof, x_minus_z)
+}
+
+/// Checks `e(sum r^i (C_i - [y_i] + [z_i] proof_i), [1]) == e(sum r^i proof_i, [tau])`.
+fn verify_kzg_proof_batch_impl<K: KzgBackend>(
+    commitments: &[K::G1],
+    zs: &[K::Scalar],
+    ys: &[K::Scalar],
+    proofs: &[K::G1],
+    r: &K::Scalar,
+    g2_tau: G2Affine,
+) -> bool {
+    let (lhs, proof_lincomb) = batch_lincombs::<K>(commitments, zs, ys, proofs, r);
+
+    K::pairings_verify(
+        lhs,
+        K::g2_generator(),
+        proof_lincomb,
+        K::g2_from_affine(&g2_tau),
+    )
+}
+
+/// Returns the roots of unity of `kzg_settings` after checking that they match the domain of `P`.
+fn check_domain<P: KzgPreset>(kzg_settings: &impl VerifierSetup) -> Result<&[Scalar], KzgError> {
+    let roots_of_unity = kzg_settings
+        .roots_of_unity()
+        .ok_or(TrustedSetupError::MissingRootsOfUnity)?;
+    if roots_of_unity.len() != P::FIELD_ELEMENTS_PER_BLOB {
+        return Err(TrustedSetupError::RootsOfUnityCount {
+            expected: P::FIELD_ELEMENTS_PER_BLOB,
+            actual: roots_of_unity.len(),
+        }
+        .into());
+    }
+    Ok(roots_of_unity)
+}
 
-    let [p0, q0] = [p0, q0].map(|p| {
-        let (x, y) = p.into_coords();
-        AffinePoint::new(x, y)
-    });
-    let g1_points = [-p0, q0];
-    let g2_points = [p1, q1].map(Into::into);
+/// Computes the Fiat-Shamir challenge at which a blob proof opens the blob's polynomial, as in
+/// the consensus-spec `compute_challenge`.
+pub fn compute_challenge<B: AsBlobRef>(blob: &B, commitment_bytes: &Bytes48) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update(B::Preset::FIAT_SHAMIR_PROTOCOL_DOMAIN.as_bytes());
+    hasher.update((B::Preset::FIELD_ELEMENTS_PER_BLOB as u128).to_be_bytes());
+    hasher.update(blob.as_blob_ref().as_slice());
+    hasher.update(commitment_bytes.as_slice());
+    hash_to_scalar(hasher.finalize().into())
+}
 
-    Bls12_381::pairing_check(&g1_points, &g2_points).is_ok()
+/// Computes the random challenge that combines the proofs of a batch.
+fn compute_batch_challenge<P: KzgPreset, S: KzgScalar>(
+    commitments_bytes: &[impl AsRef<Bytes48>],
+    zs: &[S],
+    ys: &[S],
+    proofs_bytes: &[impl AsRef<Bytes48>],
+) -> Scalar {
+    let mut hasher = Sha256::new();
+    hasher.update(P::RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.as_bytes());
+    hasher.update((P::FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes());
+    hasher.update((commitments_bytes.len() as u64).to_be_bytes());
+    for (((commitment_bytes, z), y), proof_bytes) in
+        commitments_bytes.iter().zip(zs).zip(ys).zip(proofs_bytes)
+    {
+        hasher.update(commitment_bytes.as_ref().as_slice());
+        hasher.update(z.to_be_bytes32());
+        hasher.update(y.to_be_bytes32());
+        hasher.update(proof_bytes.as_ref().as_slice());
+    }
+    hash_to_scalar(hasher.finalize().into())
+}
+
+/// Interprets a hash as a big-endian integer reduced modulo the scalar field order.
+pub(crate) fn hash_to_scalar(hash: [u8; 32]) -> Scalar {
+    let mut wide = [0u8; 64];
+    wide[..32].copy_from_slice(&hash);
+    wide[..32].reverse();
+    Scalar::from_bytes_wide(&wide)
 }
 
 pub fn g1_affine_is_on_curve(p: &AffinePoint<Fp>) -> bool {
@@ -169,28 +392,6 @@ pub fn g2_affine_is_on_curve(p: &AffinePoint<Fp2>) -> bool {
 
 // Conversion functions:
 
-/// Assumes that G2Affine is a point on the curve in the correct subgroup.
-fn to_openvm_g2_affine(g2: G2Affine) -> Bls12_381G2Affine {
-    if g2.is_identity().unwrap_u8() != 0 {
-        return <Bls12_381G2Affine as Group>::IDENTITY;
-    }
-    let g2_bytes = g2.to_uncompressed();
-    let x_c1: [u8; 48] = g2_bytes[0..48].try_into().unwrap();
-    let x_c0: [u8; 48] = g2_bytes[48..96].try_into().unwrap();
-    let y_c1: [u8; 48] = g2_bytes[96..144].try_into().unwrap();
-    let y_c0: [u8; 48] = g2_bytes[144..192].try_into().unwrap();
-
-    let ox = Fp2::from_coeffs([
-        Fp::from_be_bytes_unchecked(&x_c0),
-        Fp::from_be_bytes_unchecked(&x_c1),
-    ]);
-    let oy = Fp2::from_coeffs([
-        Fp::from_be_bytes_unchecked(&y_c0),
-        Fp::from_be_bytes_unchecked(&y_c1),
-    ]);
-    Bls12_381G2Affine::from_xy_unchecked(ox, oy)
-}
-
 /// Returns true if the field element is lexicographically larger than its negation.
 ///
 /// The input `y` does not need to be reduced modulo the modulus.
@@ -217,37 +418,131 @@ fn is_lex_largest(y: &Fp) -> bool {
     false
 }
 
-// hint_decompress is currently not implemented on host because of the need to do a sqrt
-#[cfg(target_os = "zkvm")]
-pub fn safe_g1_affine_from_bytes(bytes: &Bytes48) -> Result<Bls12_381G1Affine, KzgError> {
-    use openvm_ecc_guest::weierstrass::FromCompressed;
+/// A compressed G1 point whose flags and x-coordinate are well formed, before decompression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompressedG1 {
+    Identity,
+    /// A point with the reduced x-coordinate `x`, and the lexicographically larger of the two
+    /// y-coordinates if `largest_y`.
+    Point {
+        x: Fp,
+        largest_y: bool,
+    },
+}
 
+impl CompressedG1 {
+    /// Returns the canonical compressed encoding.
+    pub fn to_bytes(&self) -> Bytes48 {
+        let mut bytes = [0u8; 48];
+        match self {
+            Self::Identity => bytes[0] = 0b1100_0000,
+            Self::Point { x, largest_y } => {
+                bytes.copy_from_slice(&x.to_be_bytes());
+                bytes[0] |= 0b1000_0000 | (u8::from(*largest_y) << 5);
+            }
+        }
+        Bytes48(bytes)
+    }
+}
+
+/// Parses the flags and the x-coordinate of a compressed G1 point, with the checks that
+/// `G1Affine::from_compressed_unchecked` makes before decompressing: the compression flag must be
+/// set, the identity must have neither the sort flag nor an x-coordinate, and the x-coordinate of
+/// any other point must be reduced.
+///
+/// The guest decoder decompresses the result; this part compiles on the host, so that it is tested
+/// against `bls12_381`.
+pub fn parse_compressed_g1(bytes: &Bytes48) -> Result<CompressedG1, KzgError> {
     let mut x_bytes = [0u8; 48];
     x_bytes.copy_from_slice(&bytes.0[0..48]);
 
     let compression_flag_set = ((x_bytes[0] >> 7) & 1) != 0;
     let infinity_flag_set = ((x_bytes[0] >> 6) & 1) != 0;
     let sort_flag_set = ((x_bytes[0] >> 5) & 1) != 0;
+    if !compression_flag_set {
+        return Err(KzgError::BadArgs(InputError::InvalidPointEncoding));
+    }
 
     // Mask away the flag bits
     x_bytes[0] &= 0b0001_1111;
-    let x = Fp::from_be_bytes(&x_bytes)
-        .ok_or_else(|| KzgError::BadArgs("x bytes not in canonical form".to_string()))?;
+    if infinity_flag_set {
+        // The identity has no sign and an x-coordinate of zero.
+        if sort_flag_set || x_bytes != [0u8; 48] {
+            return Err(KzgError::BadArgs(InputError::InvalidPointEncoding));
+        }
+        return Ok(CompressedG1::Identity);
+    }
+    let x =
+        Fp::from_be_bytes(&x_bytes).ok_or(KzgError::BadArgs(InputError::InvalidPointEncoding))?;
+    Ok(CompressedG1::Point {
+        x,
+        largest_y: sort_flag_set,
+    })
+}
 
-    if infinity_flag_set && compression_flag_set && !sort_flag_set && x == Fp::ZERO {
-        return Ok(<Bls12_381G1Affine as Group>::IDENTITY);
+// hint_decompress is currently not implemented on host because of the need to do a sqrt
+/// Decodes a compressed G1 point, accepting exactly the encodings that
+/// [`safe_g1_affine_from_bytes_native`] accepts and returning the same errors.
+#[cfg(target_os = "zkvm")]
+pub fn safe_g1_affine_from_bytes(bytes: &Bytes48) -> Result<Bls12_381G1Affine, KzgError> {
+    let point = decompress_g1(bytes)?;
+    if !is_in_g1_subgroup(&point) {
+        return Err(KzgError::BadArgs(InputError::PointNotInSubgroup));
     }
+    Ok(point)
+}
+
+/// Decodes a compressed G1 point that is known to be valid, skipping the subgroup check.
+///
+/// Panics if the point does not decompress.
+#[cfg(target_os = "zkvm")]
+pub(crate) fn g1_affine_from_validated_bytes(bytes: &Bytes48) -> Bls12_381G1Affine {
+    decompress_g1(bytes).expect("validated on construction")
+}
+
+/// Decompresses a G1 point without checking that it is in the subgroup.
+#[cfg(target_os = "zkvm")]
+fn decompress_g1(bytes: &Bytes48) -> Result<Bls12_381G1Affine, KzgError> {
+    use openvm_ecc_guest::weierstrass::FromCompressed;
+
+    let (x, largest_y) = match parse_compressed_g1(bytes)? {
+        CompressedG1::Identity => return Ok(<Bls12_381G1Affine as Group>::IDENTITY),
+        CompressedG1::Point { x, largest_y } => (x, largest_y),
+    };
 
     // Note that we need to determine the y-coord using lexicographic ordering instead of parity, so
     // the value for rec_id does not matter and we can pass in either 0 or 1.
     let mut point = Bls12_381G1Affine::decompress(x, &0u8)
-        .ok_or_else(|| KzgError::BadArgs("Failed to decompress G1Affine".to_string()))?;
-    if is_lex_largest(point.y()) ^ sort_flag_set {
+        .ok_or(KzgError::BadArgs(InputError::InvalidPointEncoding))?;
+    if is_lex_largest(point.y()) ^ largest_y {
         point.y_mut().neg_assign();
     }
     Ok(point)
 }
 
+/// Checks that `φ(p) == -[x^2] p`, where `φ(x, y) = (βx, y)` and `x` is the BLS parameter, which
+/// holds exactly for the points of the G1 subgroup (Section 6 of <https://eprint.iacr.org/2021/1130>).
+#[cfg(target_os = "zkvm")]
+fn is_in_g1_subgroup(point: &Bls12_381G1Affine) -> bool {
+    // A primitive cube root of unity of Fp, little-endian.
+    const BETA: Fp = Fp::from_const_bytes(hex!("FEFFFEFFFFFF012E02000A6213D817DE8896F8E63BA9B3DDEA770F6A07C669BA51CE76DF2F67195F0000000000000000"));
+    // x is negative, and the signs cancel in x^2.
+    const ABS_X: u64 = 0xd201_0000_0001_0000;
+
+    let mul_by_abs_x = |point: &Bls12_381G1Affine| {
+        let mut acc = <Bls12_381G1Affine as Group>::IDENTITY;
+        for i in (0..u64::BITS).rev() {
+            acc = acc.double();
+            if (ABS_X >> i) & 1 == 1 {
+                acc = acc + point.clone();
+            }
+        }
+        acc
+    };
+    let endomorphism = Bls12_381G1Affine::from_xy_unchecked(point.x() * &BETA, point.y().clone());
+    mul_by_abs_x(&mul_by_abs_x(point)) == -endomorphism
+}
+
 /// Assumes that G1Affine is a point on the curve in the correct subgroup.
 #[cfg(not(target_os = "zkvm"))]
 fn to_openvm_g1_affine(g1: G1Affine) -> Bls12_381G1Affine {
@@ -266,10 +561,27 @@ pub fn safe_g1_affine_from_bytes(bytes: &Bytes48) -> Result<Bls12_381G1Affine, K
     Ok(to_openvm_g1_affine(g1))
 }
 
+#[cfg(not(target_os = "zkvm"))]
+pub(crate) fn g1_affine_from_validated_bytes(bytes: &Bytes48) -> Bls12_381G1Affine {
+    to_openvm_g1_affine(g1_affine_from_validated_bytes_native(bytes))
+}
+
 pub fn safe_g1_affine_from_bytes_native(bytes: &Bytes48) -> Result<G1Affine, KzgError> {
-    let g1 = G1Affine::from_compressed(&bytes.0);
-    g1.into_option()
-        .ok_or_else(|| KzgError::BadArgs("Failed to parse G1Affine from bytes".to_string()))
+    // Decoding without the subgroup check first tells a bad encoding apart from a point outside
+    // the subgroup.
+    let g1 = Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes.0))
+        .ok_or(KzgError::BadArgs(InputError::InvalidPointEncoding))?;
+    if !bool::from(g1.is_torsion_free()) {
+        return Err(KzgError::BadArgs(InputError::PointNotInSubgroup));
+    }
+    Ok(g1)
+}
+
+/// Decodes a compressed G1 point that is known to be valid, skipping the subgroup check.
+///
+/// Panics if the point does not decompress.
+pub(crate) fn g1_affine_from_validated_bytes_native(bytes: &Bytes48) -> G1Affine {
+    Option::from(G1Affine::from_compressed_unchecked(&bytes.0)).expect("validated on construction")
 }
 
 pub fn safe_scalar_affine_from_bytes(bytes: &Bytes32) -> Result<Scalar, KzgError> {
@@ -284,9 +596,7 @@ pub fn safe_scalar_affine_from_bytes(bytes: &Bytes32) -> Result<Scalar, KzgError
 
     let scalar = Scalar::from_bytes(&lendian);
     if scalar.is_none().into() {
-        return Err(KzgError::BadArgs(
-            "Failed to parse Scalar from bytes32".to_string(),
-        ));
+        return Err(KzgError::BadArgs(InputError::NonCanonicalScalar));
     }
     Ok(scalar.unwrap())
 }
@@ -321,17 +631,20 @@ fn convert_g2(g2: &AffinePoint<Fp2>) -> G2Affine {
 pub mod tests {
     use super::*;
     use crate::{
-        test_files::VERIFY_KZG_PROOF_TESTS,
-        test_utils::{Input, Test},
+        backend::{IntrinsicBackend, NativeBackend},
+        get_kzg_point_verifier_settings, get_kzg_verifier_settings,
+        test_utils::InsecureTrustedSetup,
+        test_vectors::{test_vectors, VerifyBlobKzgProof, VerifyBlobKzgProofBatch, VerifyKzgProof},
+        KzgSettings, Minimal,
     };
+    use proptest::prelude::*;
 
     // This is a test on host
     #[test]
     pub fn test_verify_kzg_proof() {
         let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
 
-        for (test_file, data) in &VERIFY_KZG_PROOF_TESTS {
-            let test: Test<Input> = serde_yaml::from_str(data).unwrap();
+        for (test_file, test) in test_vectors::<VerifyKzgProof>() {
             let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
                 test.input.get_commitment(),
                 test.input.get_z(),
@@ -344,8 +657,509 @@ pub mod tests {
 
             let result = KzgProof::verify_kzg_proof(&commitment, &z, &y, &proof, &kzg_settings);
             println!("test: {test_file}: {result:?}");
-            let is_ok = result.unwrap_or(false);
-            assert_eq!(is_ok, test.get_output().unwrap_or(false));
+            let slim_result = KzgProof::verify_kzg_proof(
+                &commitment,
+                &z,
+                &y,
+                &proof,
+                &get_kzg_point_verifier_settings(),
+            );
+            assert_eq!(result, slim_result);
+            // Both backends are in every build and agree.
+            for backend_result in [
+                KzgVerifier::<IntrinsicBackend>::verify_kzg_proof(
+                    &commitment,
+                    &z,
+                    &y,
+                    &proof,
+                    &kzg_settings,
+                ),
+                KzgVerifier::<NativeBackend>::verify_kzg_proof(
+                    &commitment,
+                    &z,
+                    &y,
+                    &proof,
+                    &kzg_settings,
+                ),
+            ] {
+                assert_eq!(backend_result, result);
+            }
+            // The typed arguments reject exactly the inputs that fail to decode.
+            match (
+                KzgCommitment::new(commitment),
+                FieldElement::new(z),
+                FieldElement::new(y),
+                KzgProofBytes::new(proof),
+            ) {
+                (Ok(commitment), Ok(z), Ok(y), Ok(proof)) => assert_eq!(
+                    KzgProof::verify_kzg_proof_typed(&commitment, &z, &y, &proof, &kzg_settings),
+                    result
+                ),
+                _ => assert!(!result.unwrap().is_well_formed()),
+            }
+            assert_eq!(VerificationOutcome::spec_output(&result), test.get_output());
+        }
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (test_file, test) in test_vectors::<VerifyBlobKzgProof>() {
+            let (Ok(blob), Ok(commitment), Ok(proof)) = (
+                test.input.get_blob(),
+                test.input.get_commitment(),
+                test.input.get_proof(),
+            ) else {
+                assert!(test.get_output().is_none());
+                continue;
+            };
+
+            let result = KzgProof::verify_blob_kzg_proof(&blob, &commitment, &proof, &kzg_settings);
+            println!("test: {test_file}: {result:?}");
+            assert_eq!(VerificationOutcome::spec_output(&result), test.get_output());
+            let slim_result = KzgProof::verify_blob_kzg_proof(
+                &blob,
+                &commitment,
+                &proof,
+                &get_kzg_verifier_settings(),
+            );
+            assert_eq!(slim_result, result);
+            // Blob verification needs the roots of unity.
+            assert!(KzgProof::verify_blob_kzg_proof(
+                &blob,
+                &commitment,
+                &proof,
+                &get_kzg_point_verifier_settings()
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_batch() {
+        let kzg_settings = KzgSettings::load_trusted_setup_file().unwrap();
+
+        for (test_file, test) in test_vectors::<VerifyBlobKzgProofBatch>() {
+            let (Ok(blobs), Ok(commitments), Ok(proofs)) = (
+                test.input.get_blobs(),
+                test.input.get_commitments(),
+                test.input.get_proofs(),
+            ) else {
+                assert!(test.get_output().is_none());
+                continue;
+            };
+
+            let result =
+                KzgProof::verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &kzg_settings);
+            println!("test: {test_file}: {result:?}");
+            assert_eq!(VerificationOutcome::spec_output(&result), test.get_output());
+            assert_eq!(
+                KzgVerifier::<IntrinsicBackend>::verify_blob_kzg_proof_batch(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    &kzg_settings
+                ),
+                result
+            );
+            assert_eq!(
+                KzgVerifier::<NativeBackend>::verify_blob_kzg_proof_batch(
+                    &blobs,
+                    &commitments,
+                    &proofs,
+                    &kzg_settings
+                ),
+                result
+            );
+            // The typed arguments skip the checks of the points, which passed on construction.
+            let typed_commitments: Result<Vec<_>, _> = commitments
+                .iter()
+                .cloned()
+                .map(KzgCommitment::new)
+                .collect();
+            let typed_proofs: Result<Vec<_>, _> =
+                proofs.iter().cloned().map(KzgProofBytes::new).collect();
+            if let (Ok(typed_commitments), Ok(typed_proofs)) = (typed_commitments, typed_proofs) {
+                for typed_result in [
+                    KzgProof::verify_blob_kzg_proof_batch_typed(
+                        &blobs,
+                        &typed_commitments,
+                        &typed_proofs,
+                        &kzg_settings,
+                    ),
+                    KzgVerifier::<NativeBackend>::verify_blob_kzg_proof_batch_typed(
+                        &blobs,
+                        &typed_commitments,
+                        &typed_proofs,
+                        &kzg_settings,
+                    ),
+                ] {
+                    assert_eq!(typed_result, result);
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_verify_blob_kzg_proof_minimal() {
+        let kzg_settings = Minimal::kzg_settings();
+        let blobs: Vec<Blob<Minimal>> = (1u8..=3)
+            .map(|seed| {
+                let mut bytes = [0u8; crate::BYTES_PER_BLOB_MINIMAL];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    // Keep the top byte of each field element zero so that it is canonical.
+                    if i % crate::BYTES_PER_FIELD_ELEMENT != 0 {
+                        *byte = seed.wrapping_mul(i as u8).wrapping_add(7);
+                    }
+                }
+                Blob::from_slice(&bytes).unwrap()
+            })
+            .collect();
+        // The minimal setup is generated from the same secret.
+        let setup = InsecureTrustedSetup::generate(Scalar::from(1337u64), 4).unwrap();
+        let (commitments, proofs): (Vec<_>, Vec<_>) = blobs
+            .iter()
+            .map(|blob| setup.compute_blob_kzg_proof(blob).unwrap())
+            .unzip();
+
+        for ((blob, commitment), proof) in blobs.iter().zip(&commitments).zip(&proofs) {
+            assert!(
+                KzgProof::verify_blob_kzg_proof(blob, commitment, proof, &kzg_settings)
+                    .unwrap()
+                    .is_valid()
+            );
+        }
+        assert!(KzgProof::verify_blob_kzg_proof_batch(
+            &blobs,
+            &commitments,
+            &proofs,
+            &kzg_settings
+        )
+        .unwrap()
+        .is_valid());
+        assert!(!KzgProof::verify_blob_kzg_proof(
+            &blobs[0],
+            &commitments[0],
+            &proofs[1],
+            &kzg_settings
+        )
+        .unwrap()
+        .is_valid());
+        let swapped = [proofs[1].clone(), proofs[0].clone(), proofs[2].clone()];
+        assert!(!KzgProof::verify_blob_kzg_proof_batch(
+            &blobs,
+            &commitments,
+            &swapped,
+            &kzg_settings
+        )
+        .unwrap()
+        .is_valid());
+
+        // Blobs of the minimal preset cannot be verified against the mainnet setup.
+        let mainnet_settings = KzgSettings::load_trusted_setup_file().unwrap();
+        assert_eq!(
+            KzgProof::verify_blob_kzg_proof(
+                &blobs[0],
+                &commitments[0],
+                &proofs[0],
+                &mainnet_settings
+            ),
+            Err(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::RootsOfUnityCount {
+                    expected: 4,
+                    actual: 4096
+                }
+            ))
+        );
+
+        // Views of the same bytes verify like the owned blobs.
+        let blob_refs: Vec<BlobRef<Minimal>> =
+            blobs.iter().map(|blob| blob.as_blob_ref()).collect();
+        assert!(KzgProof::verify_blob_kzg_proof(
+            &blob_refs[0],
+            &commitments[0],
+            &proofs[0],
+            &kzg_settings
+        )
+        .unwrap()
+        .is_valid());
+        assert!(KzgProof::verify_blob_kzg_proof_batch(
+            &blob_refs,
+            &commitments,
+            &proofs,
+            &kzg_settings
+        )
+        .unwrap()
+        .is_valid());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let mut bytes = [0u8; 48];
+        // On the curve but not in the subgroup.
+        bytes[0] = 0x80;
+        bytes[47] = 4;
+        assert_eq!(
+            safe_g1_affine_from_bytes_native(&Bytes48(bytes)),
+            Err(KzgError::BadArgs(InputError::PointNotInSubgroup))
+        );
+        // Not compressed.
+        bytes[0] = 0;
+        assert_eq!(
+            safe_g1_affine_from_bytes_native(&Bytes48(bytes)),
+            Err(KzgError::BadArgs(InputError::InvalidPointEncoding))
+        );
+        assert_eq!(
+            safe_scalar_affine_from_bytes(&Bytes32([0xff; 32])),
+            Err(KzgError::BadArgs(InputError::NonCanonicalScalar))
+        );
+
+        // Verification reports why the inputs were rejected.
+        let kzg_settings = Minimal::kzg_settings();
+        let mut blob_bytes = [0u8; crate::BYTES_PER_BLOB_MINIMAL];
+        blob_bytes[31] = 1;
+        let blob = Blob::<Minimal>::from_slice(&blob_bytes).unwrap();
+        let setup = InsecureTrustedSetup::generate(Scalar::from(1337u64), 4).unwrap();
+        let (commitment, proof) = setup.compute_blob_kzg_proof(&blob).unwrap();
+        let verify = |blob: &Blob<Minimal>, commitment: &Bytes48| {
+            KzgProof::verify_blob_kzg_proof(blob, commitment, &proof, &kzg_settings).unwrap()
+        };
+        assert_eq!(verify(&blob, &commitment), VerificationOutcome::Valid);
+        assert_eq!(verify(&blob, &proof), VerificationOutcome::InvalidProof);
+        bytes[0] = 0x80;
+        assert_eq!(
+            verify(&blob, &Bytes48(bytes)),
+            VerificationOutcome::PointNotInSubgroup
+        );
+        bytes[0] = 0;
+        assert_eq!(
+            verify(&blob, &Bytes48(bytes)),
+            VerificationOutcome::MalformedEncoding
+        );
+        let non_canonical =
+            Blob::<Minimal>::from_slice(&[0xff; crate::BYTES_PER_BLOB_MINIMAL]).unwrap();
+        assert_eq!(
+            verify(&non_canonical, &commitment),
+            VerificationOutcome::NonCanonicalFieldElement
+        );
+
+        assert_eq!(
+            KzgError::BadArgs(InputError::UnreducedScalar("z")).to_string(),
+            "Scalar z is not reduced"
+        );
+    }
+
+    type IntrinsicScalar = <IntrinsicBackend as KzgBackend>::Scalar;
+
+    /// The base field modulus, big-endian.
+    const FP_MODULUS: [u8; 48] = hex_literal::hex!("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+
+    /// The scalar field modulus, big-endian.
+    fn scalar_modulus() -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_mut(8).zip(crate::MODULUS.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Adds big-endian integers, returning `None` on overflow.
+    fn add_be<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Option<[u8; N]> {
+        let mut sum = [0u8; N];
+        let mut carry = 0u16;
+        for i in (0..N).rev() {
+            let digit = a[i] as u16 + b[i] as u16 + carry;
+            sum[i] = digit as u8;
+            carry = digit >> 8;
+        }
+        (carry == 0).then_some(sum)
+    }
+
+    /// Decodes with the flag and canonicity checks of the guest decoder, decompressing natively
+    /// as the guest intrinsics cannot run on the host. Like `from_compressed_unchecked`, the
+    /// subgroup is not checked.
+    fn decode_with_guest_parser(bytes: &Bytes48) -> Result<G1Affine, KzgError> {
+        let canonical = parse_compressed_g1(bytes)?.to_bytes();
+        Option::from(G1Affine::from_compressed_unchecked(&canonical.0))
+            .ok_or(KzgError::BadArgs(InputError::InvalidPointEncoding))
+    }
+
+    #[test]
+    fn test_guest_parser_rejects_malformed_flags() {
+        let generator = G1Affine::generator().to_compressed();
+        let mut cases = Vec::new();
+        // The compression flag is cleared.
+        let mut uncompressed_flag = generator;
+        uncompressed_flag[0] &= 0x7f;
+        cases.push(uncompressed_flag);
+        // The identity with an x-coordinate, or with the sort flag.
+        let mut identity_with_x = [0u8; 48];
+        identity_with_x[0] = 0xc0;
+        identity_with_x[47] = 1;
+        cases.push(identity_with_x);
+        let mut signed_identity = [0u8; 48];
+        signed_identity[0] = 0xe0;
+        cases.push(signed_identity);
+        // The infinity flag on a point with an x-coordinate.
+        let mut infinity_flag = generator;
+        infinity_flag[0] |= 0x40;
+        cases.push(infinity_flag);
+
+        for bytes in cases {
+            let bytes = Bytes48(bytes);
+            let expected = Err(KzgError::BadArgs(InputError::InvalidPointEncoding));
+            assert_eq!(parse_compressed_g1(&bytes), expected.clone());
+            assert_eq!(safe_g1_affine_from_bytes_native(&bytes), expected);
+        }
+        assert_eq!(
+            parse_compressed_g1(&Bytes48(generator)).unwrap().to_bytes(),
+            Bytes48(generator)
+        );
+    }
+
+    fn g1_point() -> impl Strategy<Value = G1Affine> {
+        prop_oneof![
+            1 => Just(G1Affine::identity()),
+            15 => any::<[u8; 32]>().prop_map(|bytes| {
+                let mut wide = [0u8; 64];
+                wide[..32].copy_from_slice(&bytes);
+                G1Affine::from(G1Affine::generator() * Scalar::from_bytes_wide(&wide))
+            }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_g1_roundtrip(point in g1_point()) {
+            let bytes = Bytes48(point.to_compressed());
+            prop_assert_eq!(safe_g1_affine_from_bytes_native(&bytes), Ok(point));
+            prop_assert_eq!(decode_with_guest_parser(&bytes), Ok(point));
+        }
+
+        #[test]
+        fn test_g1_decoders_agree_with_bls12_381(
+            point in g1_point(),
+            index in 0..48usize,
+            mask in any::<u8>(),
+        ) {
+            // Most single-byte changes give an encoding that does not decode, or a point on the
+            // curve outside of the subgroup.
+            let mut bytes = point.to_compressed();
+            bytes[index] ^= mask;
+            let expected = Option::<G1Affine>::from(G1Affine::from_compressed(&bytes));
+            let expected_unchecked =
+                Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes));
+            let bytes = Bytes48(bytes);
+            let native = safe_g1_affine_from_bytes_native(&bytes);
+            prop_assert_eq!(native.clone().ok(), expected);
+            let guest = decode_with_guest_parser(&bytes);
+            prop_assert_eq!(guest.clone().ok(), expected_unchecked);
+            // Both decoders reject a bad encoding with the same error.
+            if let Err(e) = guest {
+                prop_assert_eq!(native, Err(e));
+            }
+        }
+
+        #[test]
+        fn test_g1_rejects_non_canonical(point in g1_point()) {
+            let compressed = point.to_compressed();
+            let mut non_canonical = Vec::new();
+
+            // x + p has the same residue as x.
+            let mut x = compressed;
+            x[0] &= 0x1f;
+            if let Some(mut shifted) = add_be(&x, &FP_MODULUS) {
+                if shifted[0] & 0xe0 == 0 {
+                    shifted[0] |= compressed[0] & 0xe0;
+                    non_canonical.push(shifted);
+                }
+            }
+            // Without the compression flag.
+            let mut uncompressed_flag = compressed;
+            uncompressed_flag[0] &= 0x7f;
+            non_canonical.push(uncompressed_flag);
+            if bool::from(point.is_identity()) {
+                // The identity has no sign, and no x.
+                let mut signed = compressed;
+                signed[0] |= 0x20;
+                non_canonical.push(signed);
+                let mut with_x = compressed;
+                with_x[47] = 1;
+                non_canonical.push(with_x);
+            } else {
+                let mut infinity_flag = compressed;
+                infinity_flag[0] |= 0x40;
+                non_canonical.push(infinity_flag);
+            }
+
+            for bytes in non_canonical {
+                let bytes = Bytes48(bytes);
+                let expected = Err(KzgError::BadArgs(InputError::InvalidPointEncoding));
+                prop_assert_eq!(safe_g1_affine_from_bytes_native(&bytes), expected.clone());
+                prop_assert_eq!(parse_compressed_g1(&bytes), expected);
+            }
+        }
+
+        #[test]
+        fn test_scalar_decoding(bytes in any::<[u8; 32]>()) {
+            let bytes = Bytes32(bytes);
+            let canonical = bytes.0 < scalar_modulus();
+            let native = safe_scalar_affine_from_bytes(&bytes);
+            let intrinsic = IntrinsicScalar::from_be_bytes32(&bytes);
+            prop_assert_eq!(native.is_ok(), canonical);
+            prop_assert_eq!(
+                Option::<Scalar>::from(Scalar::from_bytes(&{
+                    let mut le = bytes.0;
+                    le.reverse();
+                    le
+                })),
+                native.clone().ok()
+            );
+            prop_assert_eq!(
+                intrinsic.clone(),
+                native.as_ref().ok().map(IntrinsicScalar::from_scalar)
+            );
+            if let (Ok(native), Some(intrinsic)) = (native, intrinsic) {
+                prop_assert_eq!(native.to_be_bytes32(), bytes.0);
+                prop_assert_eq!(intrinsic.to_be_bytes32(), bytes.0);
+            }
+        }
+
+        #[test]
+        fn test_blob_decoding(
+            mut bytes in proptest::collection::vec(any::<u8>(), crate::BYTES_PER_BLOB_MINIMAL),
+            clear_top_bytes in any::<bool>(),
+        ) {
+            if clear_top_bytes {
+                for element in bytes.chunks_mut(crate::BYTES_PER_FIELD_ELEMENT) {
+                    element[0] = 0;
+                }
+            }
+            let blob = Blob::<Minimal>::from_slice(&bytes).unwrap();
+            let canonical = bytes
+                .chunks(crate::BYTES_PER_FIELD_ELEMENT)
+                .all(|element| element < &scalar_modulus()[..]);
+
+            let polynomial = blob.as_polynomial();
+            prop_assert_eq!(polynomial.is_ok(), canonical);
+            prop_assert_eq!(
+                Polynomial::<Scalar>::from_blob(&blob).ok(),
+                polynomial.clone().ok().map(Polynomial::new)
+            );
+            prop_assert_eq!(
+                Polynomial::<IntrinsicScalar>::from_blob(&blob).ok(),
+                polynomial.as_ref().ok().map(|evaluations| {
+                    Polynomial::<IntrinsicScalar>::new(
+                        evaluations.iter().map(IntrinsicScalar::from_scalar).collect(),
+                    )
+                })
+            );
+            if let Ok(evaluations) = polynomial {
+                let encoded: Vec<u8> =
+                    evaluations.iter().flat_map(KzgScalar::to_be_bytes32).collect();
+                prop_assert_eq!(encoded, bytes);
+            }
         }
     }
 }
@@ -0,0 +1,309 @@
+//! The OP Stack blob encoding, version 0, as used by its batcher and derivation pipeline.
+//!
+//! The data is prefixed with the version byte and a 3-byte big-endian length, and encoded in 1024
+//! rounds of four field elements. Each round holds 127 bytes: 31 bytes in the low bytes of every
+//! field element, and 3 more bytes split into 6-bit chunks in their top bytes. It only applies to
+//! mainnet blobs.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    AsBlobRef, Blob, BlobEncodingError, KzgError, Mainnet, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT,
+};
+
+/// The version byte of the encoding.
+pub const ENCODING_VERSION: u8 = 0;
+/// The maximum number of data bytes in one blob.
+pub const MAX_BLOB_DATA_SIZE: usize = (4 * 31 + 3) * 1024 - 4;
+
+const VERSION_OFFSET: usize = 1;
+const ROUNDS: usize = 1024;
+
+/// Encodes `data` into a blob, as the OP Stack `Blob.FromData`.
+pub fn encode_blob(data: &[u8]) -> Result<Blob, KzgError> {
+    if data.len() > MAX_BLOB_DATA_SIZE {
+        return Err(BlobEncodingError::InputTooLarge {
+            len: data.len(),
+            max: MAX_BLOB_DATA_SIZE,
+        }
+        .into());
+    }
+
+    let mut blob = vec![0u8; BYTES_PER_BLOB];
+    let mut reader = Reader { data, offset: 0 };
+    let mut buf = [0u8; 31];
+    for round in 0..ROUNDS {
+        if reader.offset >= data.len() {
+            break;
+        }
+        let fe = round * 4 * BYTES_PER_FIELD_ELEMENT;
+
+        if round == 0 {
+            // The first field element starts with the version and the length.
+            buf[0] = ENCODING_VERSION;
+            buf[1..4].copy_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+            reader.read(&mut buf[4..]);
+        } else {
+            reader.read(&mut buf);
+        }
+        let x = reader.read_byte();
+        blob[fe] = x & 0b0011_1111;
+        blob[fe + 1..fe + 32].copy_from_slice(&buf);
+
+        reader.read(&mut buf);
+        let y = reader.read_byte();
+        blob[fe + 32] = (y & 0b0000_1111) | ((x & 0b1100_0000) >> 2);
+        blob[fe + 33..fe + 64].copy_from_slice(&buf);
+
+        reader.read(&mut buf);
+        let z = reader.read_byte();
+        blob[fe + 64] = z & 0b0011_1111;
+        blob[fe + 65..fe + 96].copy_from_slice(&buf);
+
+        reader.read(&mut buf);
+        blob[fe + 96] = ((z & 0b1100_0000) >> 2) | ((y & 0b1111_0000) >> 4);
+        blob[fe + 97..fe + 128].copy_from_slice(&buf);
+    }
+    Ok(Blob::try_from(blob).expect("the blob has BYTES_PER_BLOB bytes"))
+}
+
+/// Decodes the data of a blob, as the OP Stack `Blob.ToData`, including its checks that all bytes
+/// past the data are zero.
+pub fn decode_blob<B: AsBlobRef<Preset = Mainnet>>(blob: &B) -> Result<Vec<u8>, KzgError> {
+    let blob = blob.as_blob_ref().as_slice();
+    if blob[VERSION_OFFSET] != ENCODING_VERSION {
+        return Err(BlobEncodingError::Version {
+            expected: ENCODING_VERSION,
+            actual: blob[VERSION_OFFSET],
+        }
+        .into());
+    }
+    let output_len = u32::from_be_bytes([0, blob[2], blob[3], blob[4]]) as usize;
+    if output_len > MAX_BLOB_DATA_SIZE {
+        return Err(BlobEncodingError::Length {
+            len: output_len,
+            max: MAX_BLOB_DATA_SIZE,
+        }
+        .into());
+    }
+
+    // Round 0 only has 27 bytes of data in its first field element, after the version and the
+    // length. As upstream, the top bits of that field element are not checked.
+    let mut output = vec![0u8; MAX_BLOB_DATA_SIZE];
+    output[0..27].copy_from_slice(&blob[5..32]);
+    let mut opos = 28;
+    let mut ipos = 32;
+    let mut encoded = [blob[0], 0, 0, 0];
+    for encoded_byte in &mut encoded[1..] {
+        *encoded_byte = decode_field_element(blob, &mut opos, &mut ipos, &mut output)?;
+    }
+    opos = reassemble_bytes(opos, &encoded, &mut output);
+
+    for _ in 1..ROUNDS {
+        if opos >= output_len {
+            break;
+        }
+        for encoded_byte in &mut encoded {
+            *encoded_byte = decode_field_element(blob, &mut opos, &mut ipos, &mut output)?;
+        }
+        opos = reassemble_bytes(opos, &encoded, &mut output);
+    }
+
+    if output[output_len..].iter().any(|byte| *byte != 0) {
+        return Err(BlobEncodingError::ExtraneousData {
+            field_element: opos / BYTES_PER_FIELD_ELEMENT,
+        }
+        .into());
+    }
+    if blob[ipos..].iter().any(|byte| *byte != 0) {
+        return Err(BlobEncodingError::ExtraneousBytes { byte: ipos }.into());
+    }
+    output.truncate(output_len);
+    Ok(output)
+}
+
+/// Copies the low 31 bytes of the field element at `ipos` to `opos`, leaving a byte for the
+/// reassembled chunks, and returns its top byte.
+fn decode_field_element(
+    blob: &[u8],
+    opos: &mut usize,
+    ipos: &mut usize,
+    output: &mut [u8],
+) -> Result<u8, KzgError> {
+    if blob[*ipos] & 0b1100_0000 != 0 {
+        return Err(BlobEncodingError::InvalidFieldElement {
+            field_element: *ipos / BYTES_PER_FIELD_ELEMENT,
+        }
+        .into());
+    }
+    output[*opos..*opos + 31].copy_from_slice(&blob[*ipos + 1..*ipos + 32]);
+    let top_byte = blob[*ipos];
+    *opos += 32;
+    *ipos += 32;
+    Ok(top_byte)
+}
+
+/// Reassembles the 3 bytes split into the 6-bit chunks of the top bytes of a round.
+fn reassemble_bytes(opos: usize, encoded: &[u8; 4], output: &mut [u8]) -> usize {
+    // A round has 127 bytes of output, not 128.
+    let opos = opos - 1;
+    let x = (encoded[0] & 0b0011_1111) | ((encoded[1] & 0b0011_0000) << 2);
+    let y = (encoded[1] & 0b0000_1111) | ((encoded[3] & 0b0000_1111) << 4);
+    let z = (encoded[2] & 0b0011_1111) | ((encoded[3] & 0b0011_0000) << 2);
+    output[opos - 32] = z;
+    output[opos - 32 * 2] = y;
+    output[opos - 32 * 3] = x;
+    opos
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    /// Fills `buf` with the next bytes, padded with zeros at the end of the data.
+    fn read(&mut self, buf: &mut [u8]) {
+        let remaining = &self.data[self.offset.min(self.data.len())..];
+        let n = remaining.len().min(buf.len());
+        buf[..n].copy_from_slice(&remaining[..n]);
+        buf[n..].fill(0);
+        self.offset += n;
+    }
+
+    /// Returns the next byte, or zero at the end of the data.
+    fn read_byte(&mut self) -> u8 {
+        let mut byte = [0u8];
+        self.read(&mut byte);
+        byte[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{packing, Polynomial};
+
+    fn roundtrip(data: &[u8]) -> Blob {
+        let blob = encode_blob(data).unwrap();
+        assert_eq!(decode_blob(&blob).unwrap(), data);
+        assert_eq!(decode_blob(&blob.as_blob_ref()).unwrap(), data);
+        blob
+    }
+
+    fn pseudo_random_bytes(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 56) as u8
+            })
+            .collect()
+    }
+
+    // The cases of the upstream `TestBlobEncodeDecode` and `TestSmallBlobEncoding`.
+    #[test]
+    fn test_op_stack_encode_decode() {
+        for data in [
+            &b"this is a test of blob encoding/decoding"[..],
+            b"short",
+            b"\x00",
+            b"\x00\x01\x00",
+            b"\x00\x00\x00\x00",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            b"",
+        ] {
+            roundtrip(data);
+        }
+
+        // The first round is filled and no data remains.
+        let mut data = vec![0u8; 128];
+        data[127] = 0xff;
+        let mut expected = vec![0u8; BYTES_PER_BLOB];
+        expected[4] = 128;
+        expected[128 + 1 + 4] = 0xff;
+        assert_eq!(roundtrip(&data).as_slice(), &expected[..]);
+        let mut data = vec![0u8; 10];
+        data[9] = 0xff;
+        let mut expected = vec![0u8; BYTES_PER_BLOB];
+        expected[4] = 10;
+        expected[5 + 9] = 0xff;
+        assert_eq!(roundtrip(&data).as_slice(), &expected[..]);
+        // No bytes are left for the top bytes of the first round.
+        let mut data = vec![0u8; 27 + 31 * 3];
+        data[27 + 31 * 3 - 1] = 0xff;
+        let mut expected = vec![0u8; BYTES_PER_BLOB];
+        expected[4] = 27 + 31 * 3;
+        expected[32 * 3 + 1 + 27] = 0xff;
+        assert_eq!(roundtrip(&data).as_slice(), &expected[..]);
+
+        let blob = roundtrip(b"short");
+        let mut expected = vec![0u8; BYTES_PER_BLOB];
+        expected[..10].copy_from_slice(b"\x00\x00\x00\x00\x05short");
+        assert_eq!(blob.as_slice(), &expected[..]);
+
+        // With all bits set, every field element used has a top byte of 0x3f and 31 bytes of 0xff.
+        let all_ones = |field_elements: usize, len: [u8; 3]| {
+            let mut expected = vec![0u8; BYTES_PER_BLOB];
+            for fe in expected[..field_elements * 32].chunks_mut(32) {
+                fe[0] = 0x3f;
+                fe[1..].fill(0xff);
+            }
+            expected[1..5].copy_from_slice(&[ENCODING_VERSION, len[0], len[1], len[2]]);
+            expected
+        };
+        // Exactly the first round.
+        let blob = roundtrip(&[0xff; 123]);
+        assert_eq!(blob.as_slice(), &all_ones(4, [0, 0, 123])[..]);
+        // The full blob, with a length of 0x01fbfc.
+        let blob = roundtrip(&vec![0xff; MAX_BLOB_DATA_SIZE]);
+        assert_eq!(blob.as_slice(), &all_ones(4096, [0x01, 0xfb, 0xfc])[..]);
+
+        let blob = roundtrip(&pseudo_random_bytes(MAX_BLOB_DATA_SIZE, 99));
+        assert!(<Polynomial>::from_blob(&blob).is_ok());
+        assert!(encode_blob(&vec![0u8; MAX_BLOB_DATA_SIZE + 1]).is_err());
+        assert!(encode_blob(&vec![0u8; BYTES_PER_BLOB]).is_err());
+    }
+
+    // The cases of the upstream `TestInvalidBlobDecoding` and `TestExtraneousData`.
+    #[test]
+    fn test_op_stack_invalid_blobs() {
+        let blob = encode_blob(b"this is a test of invalid blob decoding").unwrap();
+        let decode_modified = |modify: &dyn Fn(&mut [u8])| {
+            let mut bytes = blob.as_slice().to_vec();
+            modify(&mut bytes);
+            decode_blob(&Blob::from_slice(&bytes).unwrap())
+        };
+        assert!(decode_modified(&|b| b[32] = 0b1000_0000).is_err());
+        assert_eq!(
+            decode_modified(&|b| b[VERSION_OFFSET] = 0x01),
+            Err(KzgError::InvalidBlobEncoding(BlobEncodingError::Version {
+                expected: 0,
+                actual: 1
+            }))
+        );
+        assert!(decode_modified(&|b| b[2] = 0xff).is_err());
+
+        assert!(decode_modified(&|b| b[32 * 4] = 0x01).is_err());
+        assert!(decode_modified(&|b| b[32 * 4 + 1] = 0x01).is_err());
+        assert!(decode_modified(&|b| b[BYTES_PER_BLOB - 1] = 0x01).is_err());
+        // Data past the length within the last round.
+        assert!(decode_modified(&|b| b[100] = 0x01).is_err());
+    }
+
+    #[test]
+    fn test_op_stack_matches_packing() {
+        for len in [0, 1, 1000, 100_000] {
+            let data = pseudo_random_bytes(len, len as u64);
+            let op_stack_blob = roundtrip(&data);
+            let packed = packing::encode_blobs::<Mainnet>(&data);
+            assert_eq!(packing::decode_blobs(&packed).unwrap(), data);
+            assert_eq!(
+                decode_blob(&op_stack_blob).unwrap(),
+                packing::decode_blobs(&packed).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Packing of arbitrary bytes into blobs.
+//!
+//! Every field element holds [`USABLE_BYTES_PER_FIELD_ELEMENT`] bytes after a zero top byte, so
+//! that it is always canonical. The data is prefixed with its length as a big-endian `u64`, split
+//! across as many blobs as needed, and the rest of the last blob is zero.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    AsBlobRef, Blob, BlobEncodingError, KzgError, KzgPreset, BYTES_PER_FIELD_ELEMENT,
+    USABLE_BYTES_PER_FIELD_ELEMENT,
+};
+
+const LENGTH_PREFIX_BYTES: usize = 8;
+
+/// Returns the number of data bytes one blob of preset `P` can hold.
+pub const fn usable_bytes_per_blob<P: KzgPreset>() -> usize {
+    P::FIELD_ELEMENTS_PER_BLOB * USABLE_BYTES_PER_FIELD_ELEMENT
+}
+
+/// Returns the number of blobs [`encode_blobs`] produces for `data_len` bytes. At least one blob
+/// is needed even for no data, to hold the length.
+pub fn num_blobs_for<P: KzgPreset>(data_len: usize) -> usize {
+    (LENGTH_PREFIX_BYTES + data_len).div_ceil(usable_bytes_per_blob::<P>())
+}
+
+/// Packs `data` into blobs whose field elements are all canonical.
+pub fn encode_blobs<P: KzgPreset>(data: &[u8]) -> Vec<Blob<P>> {
+    let num_blobs = num_blobs_for::<P>(data.len());
+    let mut stream = Vec::with_capacity(num_blobs * usable_bytes_per_blob::<P>());
+    stream.extend_from_slice(&(data.len() as u64).to_be_bytes());
+    stream.extend_from_slice(data);
+    stream.resize(num_blobs * usable_bytes_per_blob::<P>(), 0);
+
+    stream
+        .chunks(usable_bytes_per_blob::<P>())
+        .map(|chunk| {
+            let mut bytes = vec![0u8; P::BYTES_PER_BLOB];
+            for (element, usable) in bytes
+                .chunks_mut(BYTES_PER_FIELD_ELEMENT)
+                .zip(chunk.chunks(USABLE_BYTES_PER_FIELD_ELEMENT))
+            {
+                element[1..].copy_from_slice(usable);
+            }
+            Blob::try_from(bytes).expect("the blob has the length of the preset")
+        })
+        .collect()
+}
+
+/// Unpacks the data of blobs produced by [`encode_blobs`].
+///
+/// Fails unless the blobs are exactly an encoding: every top byte and all padding must be zero,
+/// and there must be no blobs beyond those needed for the length.
+pub fn decode_blobs<B: AsBlobRef>(blobs: &[B]) -> Result<Vec<u8>, KzgError> {
+    let mut stream = Vec::with_capacity(blobs.len() * usable_bytes_per_blob::<B::Preset>());
+    for (i, blob) in blobs.iter().enumerate() {
+        for (j, element) in blob
+            .as_blob_ref()
+            .as_slice()
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .enumerate()
+        {
+            if element[0] != 0 {
+                return Err(BlobEncodingError::NonZeroTopByte {
+                    blob: i,
+                    field_element: j,
+                }
+                .into());
+            }
+            stream.extend_from_slice(&element[1..]);
+        }
+    }
+
+    let Some((length, rest)) = stream.split_first_chunk::<LENGTH_PREFIX_BYTES>() else {
+        return Err(BlobEncodingError::NoBlobs.into());
+    };
+    let length = u64::from_be_bytes(*length);
+    if length > rest.len() as u64 {
+        return Err(BlobEncodingError::LengthExceedsCapacity {
+            length,
+            num_blobs: blobs.len(),
+        }
+        .into());
+    }
+    let (data, padding) = rest.split_at(length as usize);
+    if num_blobs_for::<B::Preset>(data.len()) != blobs.len() {
+        return Err(BlobEncodingError::BlobCount {
+            data_len: data.len(),
+            expected: num_blobs_for::<B::Preset>(data.len()),
+            actual: blobs.len(),
+        }
+        .into());
+    }
+    if padding.iter().any(|byte| *byte != 0) {
+        return Err(BlobEncodingError::NonZeroPadding.into());
+    }
+    Ok(data.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{kzg_proof::safe_scalar_affine_from_bytes, Bytes32, Mainnet, Minimal};
+    use proptest::prelude::*;
+
+    fn assert_canonical<P: KzgPreset>(blobs: &[Blob<P>]) {
+        for blob in blobs {
+            for element in blob.as_slice().chunks(BYTES_PER_FIELD_ELEMENT) {
+                let bytes = Bytes32::from_slice(element).unwrap();
+                assert!(safe_scalar_affine_from_bytes(&bytes).is_ok());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_packing_roundtrip_minimal(data in proptest::collection::vec(any::<u8>(), 0..1000)) {
+            let blobs = encode_blobs::<Minimal>(&data);
+            prop_assert_eq!(blobs.len(), num_blobs_for::<Minimal>(data.len()));
+            assert_canonical(&blobs);
+            prop_assert_eq!(decode_blobs(&blobs).unwrap(), data);
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn test_packing_roundtrip_mainnet(data in proptest::collection::vec(any::<u8>(), 0..300_000)) {
+            let blobs = encode_blobs::<Mainnet>(&data);
+            assert_canonical(&blobs);
+            let blob_refs: Vec<_> = blobs.iter().map(AsBlobRef::as_blob_ref).collect();
+            prop_assert_eq!(decode_blobs(&blob_refs).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_packing_errors() {
+        // The length prefix needs a blob even for no data.
+        assert_eq!(encode_blobs::<Minimal>(&[]).len(), 1);
+        assert_eq!(usable_bytes_per_blob::<Minimal>(), 124);
+        assert_eq!(num_blobs_for::<Minimal>(116), 1);
+        assert_eq!(num_blobs_for::<Minimal>(117), 2);
+        assert_eq!(
+            decode_blobs::<Blob<Minimal>>(&[]),
+            Err(KzgError::InvalidBlobEncoding(BlobEncodingError::NoBlobs))
+        );
+
+        let blobs = encode_blobs::<Minimal>(b"hello");
+        let mut bytes = blobs[0].as_slice().to_vec();
+        bytes[0] = 1;
+        assert!(decode_blobs(&[Blob::<Minimal>::from_slice(&bytes).unwrap()]).is_err());
+
+        let mut bytes = blobs[0].as_slice().to_vec();
+        bytes[100] = 1;
+        assert!(decode_blobs(&[Blob::<Minimal>::from_slice(&bytes).unwrap()]).is_err());
+
+        let mut bytes = blobs[0].as_slice().to_vec();
+        bytes[8] = 0xff;
+        assert!(decode_blobs(&[Blob::<Minimal>::from_slice(&bytes).unwrap()]).is_err());
+
+        // A trailing empty blob is not part of the encoding.
+        let mut blobs = blobs;
+        blobs.push(encode_blobs::<Minimal>(&[]).remove(0));
+        assert_eq!(
+            decode_blobs(&blobs),
+            Err(KzgError::InvalidBlobEncoding(
+                BlobEncodingError::BlobCount {
+                    data_len: 5,
+                    expected: 1,
+                    actual: 2
+                }
+            ))
+        );
+    }
+}
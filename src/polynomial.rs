@@ -0,0 +1,337 @@
+//! Polynomials in evaluation form over the scalar field of a backend.
+//!
+//! The scalars default to [`BackendScalar`]: the OpenVM `Scalar` with `use-intrinsics`, so that
+//! blob arithmetic in a guest runs on the modular arithmetic intrinsics, and `bls12_381::Scalar`
+//! otherwise. Any [`KzgScalar`] can be used.
+
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Mul, MulAssign};
+
+use bls12_381::Scalar;
+
+use crate::{
+    backend::{BackendScalar, KzgScalar},
+    fft::{bit_reverse_in_place, check_fft_size, fft, ifft},
+    AsBlobRef, Bytes32, InputError, KzgError, BYTES_PER_FIELD_ELEMENT,
+};
+
+/// A polynomial given by its evaluations over the bit-reversed roots of unity of a trusted setup,
+/// as decoded from a blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial<S = BackendScalar> {
+    evaluations: Vec<S>,
+}
+
+impl<S: KzgScalar> Polynomial<S> {
+    pub fn new(evaluations: Vec<S>) -> Self {
+        Self { evaluations }
+    }
+
+    /// Decodes the field elements of a blob, checking that each of them is canonical.
+    pub fn from_blob<B: AsBlobRef>(blob: &B) -> Result<Self, KzgError> {
+        let evaluations = blob
+            .as_blob_ref()
+            .as_slice()
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .enumerate()
+            .map(|(index, chunk)| {
+                S::from_be_bytes32(&Bytes32::from_slice(chunk)?).ok_or(KzgError::BadArgs(
+                    InputError::NonCanonicalBlobElement { index },
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { evaluations })
+    }
+
+    /// Evaluates the polynomial with the given coefficients, lowest degree first, over the
+    /// bit-reversed `roots_of_unity`. There must be at most one coefficient per root.
+    pub fn from_coefficients(
+        coefficients: &[S],
+        roots_of_unity: &[Scalar],
+    ) -> Result<Self, KzgError> {
+        if coefficients.len() > roots_of_unity.len() {
+            return Err(KzgError::BadArgs(InputError::TooManyCoefficients {
+                len: coefficients.len(),
+                num_roots: roots_of_unity.len(),
+            }));
+        }
+        let mut evaluations = coefficients.to_vec();
+        evaluations.resize(roots_of_unity.len(), S::zero());
+        fft(&mut evaluations, roots_of_unity)?;
+        bit_reverse_in_place(&mut evaluations);
+        Ok(Self { evaluations })
+    }
+
+    /// Interpolates the coefficients of the polynomial, lowest degree first.
+    pub fn to_coefficients(&self, roots_of_unity: &[Scalar]) -> Result<Vec<S>, KzgError> {
+        if self.evaluations.len() != roots_of_unity.len() {
+            return Err(KzgError::BadArgs(InputError::PolynomialLength {
+                len: self.evaluations.len(),
+                num_roots: roots_of_unity.len(),
+            }));
+        }
+        check_fft_size(self.evaluations.len(), roots_of_unity.len())?;
+        let mut coefficients = self.evaluations.clone();
+        bit_reverse_in_place(&mut coefficients);
+        ifft(&mut coefficients, roots_of_unity)?;
+        Ok(coefficients)
+    }
+
+    pub fn evaluations(&self) -> &[S] {
+        &self.evaluations
+    }
+
+    pub fn len(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.evaluations.is_empty()
+    }
+
+    /// Evaluates the polynomial at an arbitrary point `z` with the barycentric formula.
+    ///
+    /// `roots_of_unity` are the bit-reversed roots of unity of the trusted setup, one per
+    /// evaluation.
+    pub fn evaluate(&self, z: &S, roots_of_unity: &[Scalar]) -> Result<S, KzgError> {
+        let roots = self.backend_roots(roots_of_unity)?;
+        if let Some(i) = roots.iter().position(|root| root == z) {
+            return Ok(self.evaluations[i].clone());
+        }
+
+        let mut inverses: Vec<S> = roots.iter().map(|root| z.clone() - root.clone()).collect();
+        batch_invert(&mut inverses);
+
+        let mut result = S::zero();
+        for ((evaluation, root), inverse) in self.evaluations.iter().zip(&roots).zip(inverses) {
+            result = result + evaluation.clone() * root.clone() * inverse;
+        }
+        let width = self.evaluations.len() as u64;
+        let vanishing = pow(z, width) - S::one();
+        Ok(result * vanishing * S::from_u64(width).invert())
+    }
+
+    /// Divides `p(X) - p(z)` by `X - z`, returning the quotient in evaluation form and `p(z)`.
+    ///
+    /// The quotient is the polynomial committed to by a KZG proof of the evaluation at `z`.
+    pub fn divide_by_linear(
+        &self,
+        z: &S,
+        roots_of_unity: &[Scalar],
+    ) -> Result<(Self, S), KzgError> {
+        let y = self.evaluate(z, roots_of_unity)?;
+        let roots = self.backend_roots(roots_of_unity)?;
+        let position = roots.iter().position(|root| root == z);
+
+        // q(w_i) = (p(w_i) - y) / (w_i - z) away from z
+        let mut denominators: Vec<S> = roots
+            .iter()
+            .enumerate()
+            .map(|(i, root)| match position {
+                Some(m) if i == m => S::one(),
+                _ => root.clone() - z.clone(),
+            })
+            .collect();
+        batch_invert(&mut denominators);
+        let mut quotient: Vec<S> = self
+            .evaluations
+            .iter()
+            .zip(denominators)
+            .map(|(evaluation, inverse)| (evaluation.clone() - y.clone()) * inverse)
+            .collect();
+
+        // At z = w_m, q(w_m) = sum_{i != m} (p(w_i) - y) w_i / (z (z - w_i))
+        if let Some(m) = position {
+            // (p(w_i) - y) / (z - w_i) = -q(w_i), and q(w_m) is zero so far
+            let mut q_m = S::zero();
+            for (q_i, root) in quotient.iter().zip(&roots) {
+                q_m = q_m + q_i.clone() * root.clone();
+            }
+            quotient[m] = -(q_m * z.invert());
+        }
+        Ok((Self::new(quotient), y))
+    }
+
+    fn backend_roots(&self, roots_of_unity: &[Scalar]) -> Result<Vec<S>, KzgError> {
+        if self.evaluations.len() != roots_of_unity.len() {
+            return Err(KzgError::BadArgs(InputError::PolynomialLength {
+                len: self.evaluations.len(),
+                num_roots: roots_of_unity.len(),
+            }));
+        }
+        Ok(roots_of_unity.iter().map(S::from_scalar).collect())
+    }
+}
+
+/// Panics if the polynomials have different lengths.
+impl<S: KzgScalar> Add<&Polynomial<S>> for &Polynomial<S> {
+    type Output = Polynomial<S>;
+
+    fn add(self, rhs: &Polynomial<S>) -> Polynomial<S> {
+        let mut sum = self.clone();
+        sum += rhs;
+        sum
+    }
+}
+
+/// Panics if the polynomials have different lengths.
+impl<S: KzgScalar> AddAssign<&Polynomial<S>> for Polynomial<S> {
+    fn add_assign(&mut self, rhs: &Polynomial<S>) {
+        assert_eq!(self.len(), rhs.len(), "polynomial lengths differ");
+        for (lhs, rhs) in self.evaluations.iter_mut().zip(&rhs.evaluations) {
+            *lhs = lhs.clone() + rhs.clone();
+        }
+    }
+}
+
+impl<S: KzgScalar> Mul<&S> for &Polynomial<S> {
+    type Output = Polynomial<S>;
+
+    fn mul(self, rhs: &S) -> Polynomial<S> {
+        let mut product = self.clone();
+        product *= rhs;
+        product
+    }
+}
+
+impl<S: KzgScalar> MulAssign<&S> for Polynomial<S> {
+    fn mul_assign(&mut self, rhs: &S) {
+        for evaluation in self.evaluations.iter_mut() {
+            *evaluation = evaluation.clone() * rhs.clone();
+        }
+    }
+}
+
+/// Inverts every element of `values` with a single field inversion.
+///
+/// All elements must be non-zero.
+fn batch_invert<S: KzgScalar>(values: &mut [S]) {
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut product = S::one();
+    for value in values.iter() {
+        prefix_products.push(product.clone());
+        product = product * value.clone();
+    }
+    let mut inverse = product.invert();
+    for (value, prefix_product) in values.iter_mut().zip(prefix_products).rev() {
+        let next_inverse = inverse.clone() * value.clone();
+        *value = inverse * prefix_product;
+        inverse = next_inverse;
+    }
+}
+
+fn pow<S: KzgScalar>(base: &S, mut exponent: u64) -> S {
+    let mut result = S::one();
+    let mut base = base.clone();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base.clone();
+        }
+        base = base.clone() * base;
+        exponent >>= 1;
+    }
+    result
+}
+
+// The tests use the reference arithmetic of the concrete scalars.
+#[cfg(test)]
+#[allow(clippy::op_ref)]
+mod tests {
+    use super::*;
+    use crate::{fft::FftScalar, Blob, KzgPreset, Minimal, BYTES_PER_BLOB_MINIMAL};
+
+    fn minimal_blob(seed: u8) -> Blob<Minimal> {
+        let mut bytes = [0u8; BYTES_PER_BLOB_MINIMAL];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            if i % BYTES_PER_FIELD_ELEMENT != 0 {
+                *byte = seed.wrapping_mul(i as u8).wrapping_add(3);
+            }
+        }
+        Blob::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_polynomial_matches_native_evaluation() {
+        let kzg_settings = Minimal::kzg_settings();
+        let roots_of_unity = &kzg_settings.roots_of_unity;
+        let blob = minimal_blob(5);
+        let polynomial = <Polynomial>::from_blob(&blob).unwrap();
+        let native = blob.as_polynomial().unwrap();
+        assert_eq!(
+            polynomial.evaluations(),
+            native
+                .iter()
+                .map(BackendScalar::from_scalar)
+                .collect::<Vec<_>>()
+        );
+
+        for z in [Scalar::from(12345u64), roots_of_unity[2]] {
+            let expected = Polynomial::new(native.clone())
+                .evaluate(&z, roots_of_unity)
+                .unwrap();
+            assert_eq!(
+                polynomial
+                    .evaluate(&BackendScalar::from_scalar(&z), roots_of_unity)
+                    .unwrap(),
+                BackendScalar::from_scalar(&expected)
+            );
+            // Both backends decode and evaluate blobs alike.
+            assert_eq!(
+                Polynomial::<Scalar>::from_blob(&blob)
+                    .unwrap()
+                    .evaluate(&z, roots_of_unity)
+                    .unwrap(),
+                expected
+            );
+        }
+
+        // Field elements must be canonical.
+        let mut bytes = [0u8; BYTES_PER_BLOB_MINIMAL];
+        bytes[..BYTES_PER_FIELD_ELEMENT].fill(0xff);
+        let blob = Blob::<Minimal>::from_slice(&bytes).unwrap();
+        assert!(<Polynomial>::from_blob(&blob).is_err());
+        assert!(Polynomial::<Scalar>::from_blob(&blob).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_arithmetic() {
+        let kzg_settings = Minimal::kzg_settings();
+        let roots_of_unity = &kzg_settings.roots_of_unity;
+        let a = <Polynomial>::from_blob(&minimal_blob(1)).unwrap();
+        let b = <Polynomial>::from_blob(&minimal_blob(2)).unwrap();
+        let c = BackendScalar::from_u64(7);
+        let x = BackendScalar::from_u64(1000);
+
+        let sum = &a + &(&b * &c);
+        assert_eq!(
+            sum.evaluate(&x, roots_of_unity).unwrap(),
+            &a.evaluate(&x, roots_of_unity).unwrap()
+                + &(&b.evaluate(&x, roots_of_unity).unwrap() * &c)
+        );
+
+        // Interpolation inverts evaluation over the domain, and agrees with it outside.
+        let coefficients = a.to_coefficients(roots_of_unity).unwrap();
+        assert_eq!(
+            Polynomial::from_coefficients(&coefficients, roots_of_unity).unwrap(),
+            a
+        );
+        let mut expected = BackendScalar::zero();
+        for coefficient in coefficients.iter().rev() {
+            expected = &(&expected * &x) + coefficient;
+        }
+        assert_eq!(a.evaluate(&x, roots_of_unity).unwrap(), expected);
+
+        // p(X) - p(z) = (X - z) q(X), both at a point of the domain and outside of it.
+        for z in [
+            BackendScalar::from_u64(42),
+            BackendScalar::from_scalar(&roots_of_unity[1]),
+        ] {
+            let (quotient, y) = a.divide_by_linear(&z, roots_of_unity).unwrap();
+            assert_eq!(y, a.evaluate(&z, roots_of_unity).unwrap());
+            assert_eq!(
+                &a.evaluate(&x, roots_of_unity).unwrap() - &y,
+                &(&x - &z) * &quotient.evaluate(&x, roots_of_unity).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use core::fmt::Debug;
+
+use crate::{
+    get_kzg_settings, get_kzg_verifier_settings, get_minimal_kzg_settings, KzgSettings,
+    KzgVerifierSettings, BYTES_PER_BLOB, BYTES_PER_BLOB_MINIMAL, FIAT_SHAMIR_PROTOCOL_DOMAIN,
+    NUM_FIELD_ELEMENTS_PER_BLOB, NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL,
+    RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+};
+
+/// The parameters of a blob domain: the number of field elements per blob, the Fiat-Shamir
+/// domain separators used by blob verification, and the trusted setup embedded for it.
+///
+/// [`crate::Blob`] and the blob verification functions are generic over the preset, so that a
+/// blob of one preset cannot be verified against the domain of another.
+pub trait KzgPreset: Debug + Clone + 'static {
+    const FIELD_ELEMENTS_PER_BLOB: usize;
+    const BYTES_PER_BLOB: usize;
+    /// Domain separator for the blob evaluation challenge.
+    const FIAT_SHAMIR_PROTOCOL_DOMAIN: &'static str = FIAT_SHAMIR_PROTOCOL_DOMAIN;
+    /// Domain separator for the random challenge of batch verification.
+    const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &'static str = RANDOM_CHALLENGE_KZG_BATCH_DOMAIN;
+
+    /// Returns the trusted setup embedded for this preset.
+    fn kzg_settings() -> KzgSettings;
+
+    /// Returns the parts of the embedded trusted setup needed for verification.
+    fn kzg_verifier_settings() -> KzgVerifierSettings;
+}
+
+/// The Ethereum mainnet preset, with 4096 field elements per blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mainnet;
+
+impl KzgPreset for Mainnet {
+    const FIELD_ELEMENTS_PER_BLOB: usize = NUM_FIELD_ELEMENTS_PER_BLOB;
+    const BYTES_PER_BLOB: usize = BYTES_PER_BLOB;
+
+    fn kzg_settings() -> KzgSettings {
+        get_kzg_settings()
+    }
+
+    fn kzg_verifier_settings() -> KzgVerifierSettings {
+        get_kzg_verifier_settings()
+    }
+}
+
+/// The consensus-spec "minimal" preset, with 4 field elements per blob.
+///
+/// **WARNING:** its embedded trusted setup is generated from a publicly known secret and is only
+/// suitable for testing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Minimal;
+
+impl KzgPreset for Minimal {
+    const FIELD_ELEMENTS_PER_BLOB: usize = NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL;
+    const BYTES_PER_BLOB: usize = BYTES_PER_BLOB_MINIMAL;
+
+    fn kzg_settings() -> KzgSettings {
+        get_minimal_kzg_settings()
+    }
+
+    fn kzg_verifier_settings() -> KzgVerifierSettings {
+        // The minimal setup is small enough that there is nothing to save.
+        KzgVerifierSettings::from(&get_minimal_kzg_settings())
+    }
+}
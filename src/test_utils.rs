@@ -1,50 +1,41 @@
-use alloc::vec::Vec;
-use serde::Deserialize;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bls12_381::{G1Affine, G1Projective, G2Affine, Scalar};
+use serde::{de::Error as _, Deserialize, Deserializer};
 
-use crate::{Blob, Bytes32, Bytes48, KzgError};
+use crate::{
+    codec::impl_hex,
+    compute_roots_of_unity, encode_trusted_setup,
+    kzg_proof::{
+        compute_challenge, safe_g1_affine_from_bytes_native, safe_scalar_affine_from_bytes,
+    },
+    AsBlobRef, Blob, Bytes32, Bytes48, CeremonyError, KzgError, KzgPreset, KzgSettings, Polynomial,
+    TrustedSetupError, BYTES_PER_FIELD_ELEMENT, NUM_G2_POINTS,
+};
 
-pub trait FromHex {
-    fn from_hex(hex: &str) -> Result<Self, KzgError>
-    where
-        Self: Sized;
-}
-
-fn hex_to_bytes(hex_str: &str) -> Result<Vec<u8>, KzgError> {
-    let trimmed_str = hex_str.strip_prefix("0x").unwrap_or(hex_str);
-    hex::decode(trimmed_str)
-        .map_err(|e| KzgError::InvalidHexFormat(format!("Failed to decode hex: {}", e)))
-}
-
-impl FromHex for Bytes48 {
-    fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-        Self::from_slice(&hex_to_bytes(hex_str).unwrap())
-    }
-}
+pub use crate::FromHex;
 
-impl FromHex for Bytes32 {
-    fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-        Self::from_slice(&hex_to_bytes(hex_str).unwrap())
-    }
-}
-
-impl FromHex for Blob {
-    fn from_hex(hex_str: &str) -> Result<Self, KzgError> {
-        Self::from_slice(&hex_to_bytes(hex_str).unwrap())
-    }
-}
+/// The number of field elements in an EIP-7594 cell.
+pub const FIELD_ELEMENTS_PER_CELL: usize = 64;
+pub const BYTES_PER_CELL: usize = FIELD_ELEMENTS_PER_CELL * BYTES_PER_FIELD_ELEMENT;
 
+/// A consensus-spec test case: its input, and its output if the input is valid.
+///
+/// The output is a `bool` for the verifiers and the computed value otherwise, such as a
+/// [`Bytes48`] for `blob_to_kzg_commitment`.
 #[derive(Debug, Deserialize)]
-pub struct Test<I> {
+pub struct Test<I, O = bool> {
     pub input: I,
-    output: Option<bool>,
+    /// `None` if the input must be rejected.
+    pub output: Option<O>,
 }
 
-impl<I> Test<I> {
-    pub fn get_output(&self) -> Option<bool> {
-        self.output
+impl<I, O: Clone> Test<I, O> {
+    pub fn get_output(&self) -> Option<O> {
+        self.output.clone()
     }
 }
 
+/// The input of `verify_kzg_proof`.
 #[derive(Debug, Deserialize)]
 pub struct Input<'a> {
     commitment: &'a str,
@@ -70,3 +61,476 @@ impl Input<'_> {
         Bytes48::from_hex(self.proof)
     }
 }
+
+/// The input of `verify_blob_kzg_proof`.
+#[derive(Debug, Deserialize)]
+pub struct BlobInput<'a> {
+    blob: &'a str,
+    commitment: &'a str,
+    proof: &'a str,
+}
+
+impl BlobInput<'_> {
+    pub fn get_blob(&self) -> Result<Blob, KzgError> {
+        self.get_blob_for()
+    }
+
+    /// Returns the blob of the preset `P`, such as for the generated [`Minimal`](crate::Minimal)
+    /// cases.
+    pub fn get_blob_for<P: KzgPreset>(&self) -> Result<Blob<P>, KzgError> {
+        Blob::from_hex(self.blob)
+    }
+
+    pub fn get_commitment(&self) -> Result<Bytes48, KzgError> {
+        Bytes48::from_hex(self.commitment)
+    }
+
+    pub fn get_proof(&self) -> Result<Bytes48, KzgError> {
+        Bytes48::from_hex(self.proof)
+    }
+}
+
+/// The input of `verify_blob_kzg_proof_batch`.
+#[derive(Debug, Deserialize)]
+pub struct BlobBatchInput<'a> {
+    #[serde(borrow)]
+    blobs: Vec<&'a str>,
+    #[serde(borrow)]
+    commitments: Vec<&'a str>,
+    #[serde(borrow)]
+    proofs: Vec<&'a str>,
+}
+
+impl BlobBatchInput<'_> {
+    pub fn get_blobs(&self) -> Result<Vec<Blob>, KzgError> {
+        self.get_blobs_for()
+    }
+
+    /// Returns the blobs of the preset `P`. See [`BlobInput::get_blob_for`].
+    pub fn get_blobs_for<P: KzgPreset>(&self) -> Result<Vec<Blob<P>>, KzgError> {
+        self.blobs.iter().map(|blob| Blob::from_hex(blob)).collect()
+    }
+
+    pub fn get_commitments(&self) -> Result<Vec<Bytes48>, KzgError> {
+        self.commitments
+            .iter()
+            .map(|commitment| Bytes48::from_hex(commitment))
+            .collect()
+    }
+
+    pub fn get_proofs(&self) -> Result<Vec<Bytes48>, KzgError> {
+        self.proofs
+            .iter()
+            .map(|proof| Bytes48::from_hex(proof))
+            .collect()
+    }
+}
+
+/// The input of `blob_to_kzg_commitment`, `compute_cells` and `compute_cells_and_kzg_proofs`.
+#[derive(Debug, Deserialize)]
+pub struct SingleBlobInput<'a> {
+    blob: &'a str,
+}
+
+impl SingleBlobInput<'_> {
+    pub fn get_blob(&self) -> Result<Blob, KzgError> {
+        Blob::from_hex(self.blob)
+    }
+}
+
+/// The input of `compute_kzg_proof`.
+#[derive(Debug, Deserialize)]
+pub struct BlobPointInput<'a> {
+    blob: &'a str,
+    z: &'a str,
+}
+
+impl BlobPointInput<'_> {
+    pub fn get_blob(&self) -> Result<Blob, KzgError> {
+        Blob::from_hex(self.blob)
+    }
+
+    pub fn get_z(&self) -> Result<Bytes32, KzgError> {
+        Bytes32::from_hex(self.z)
+    }
+}
+
+/// The input of `compute_blob_kzg_proof` and `compute_challenge`.
+#[derive(Debug, Deserialize)]
+pub struct BlobCommitmentInput<'a> {
+    blob: &'a str,
+    commitment: &'a str,
+}
+
+impl BlobCommitmentInput<'_> {
+    pub fn get_blob(&self) -> Result<Blob, KzgError> {
+        Blob::from_hex(self.blob)
+    }
+
+    pub fn get_commitment(&self) -> Result<Bytes48, KzgError> {
+        Bytes48::from_hex(self.commitment)
+    }
+}
+
+/// The input of `verify_cell_kzg_proof_batch`.
+#[derive(Debug, Deserialize)]
+pub struct CellBatchInput<'a> {
+    #[serde(borrow)]
+    commitments: Vec<&'a str>,
+    cell_indices: Vec<u64>,
+    #[serde(borrow)]
+    cells: Vec<&'a str>,
+    #[serde(borrow)]
+    proofs: Vec<&'a str>,
+}
+
+impl CellBatchInput<'_> {
+    pub fn get_commitments(&self) -> Result<Vec<Bytes48>, KzgError> {
+        self.commitments
+            .iter()
+            .map(|commitment| Bytes48::from_hex(commitment))
+            .collect()
+    }
+
+    pub fn get_cell_indices(&self) -> &[u64] {
+        &self.cell_indices
+    }
+
+    pub fn get_cells(&self) -> Result<Vec<Cell>, KzgError> {
+        self.cells.iter().map(|cell| Cell::from_hex(cell)).collect()
+    }
+
+    pub fn get_proofs(&self) -> Result<Vec<Bytes48>, KzgError> {
+        self.proofs
+            .iter()
+            .map(|proof| Bytes48::from_hex(proof))
+            .collect()
+    }
+}
+
+/// The input of `recover_cells_and_kzg_proofs`.
+#[derive(Debug, Deserialize)]
+pub struct CellRecoveryInput<'a> {
+    cell_indices: Vec<u64>,
+    #[serde(borrow)]
+    cells: Vec<&'a str>,
+}
+
+impl CellRecoveryInput<'_> {
+    pub fn get_cell_indices(&self) -> &[u64] {
+        &self.cell_indices
+    }
+
+    pub fn get_cells(&self) -> Result<Vec<Cell>, KzgError> {
+        self.cells.iter().map(|cell| Cell::from_hex(cell)).collect()
+    }
+}
+
+/// An EIP-7594 cell of [`BYTES_PER_CELL`] bytes, as in the cell test formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell(Box<[u8]>);
+
+impl Cell {
+    pub fn from_slice(slice: &[u8]) -> Result<Self, KzgError> {
+        if slice.len() != BYTES_PER_CELL {
+            return Err(KzgError::InvalidBytesLength {
+                expected: BYTES_PER_CELL,
+                actual: slice.len(),
+            });
+        }
+        Ok(Self(slice.into()))
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl_hex!(Cell);
+
+impl<'de> Deserialize<'de> for Cell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Self::from_hex(&hex).map_err(D::Error::custom)
+    }
+}
+
+/// Commits to `blob` with the Lagrange points of `kzg_settings`, as `blob_to_kzg_commitment`.
+///
+/// A slow reference implementation for tests, such as the conformance runner.
+pub fn blob_to_kzg_commitment<B: AsBlobRef>(
+    blob: &B,
+    kzg_settings: &KzgSettings,
+) -> Result<Bytes48, KzgError> {
+    let polynomial = blob.as_blob_ref().as_polynomial()?;
+    let commitment = commit_in_lagrange_form(&polynomial, &kzg_settings.g1_points)?;
+    Bytes48::from_slice(&commitment.to_compressed())
+}
+
+/// Proves the evaluation of `blob` at `z`, returning the proof and the evaluation, as
+/// `compute_kzg_proof`.
+pub fn compute_kzg_proof<B: AsBlobRef>(
+    blob: &B,
+    z: &Bytes32,
+    kzg_settings: &KzgSettings,
+) -> Result<(Bytes48, Bytes32), KzgError> {
+    let polynomial = blob.as_blob_ref().as_polynomial()?;
+    let z = safe_scalar_affine_from_bytes(z)?;
+    let (proof, y) = prove_in_lagrange_form(polynomial, &z, kzg_settings)?;
+    let mut y_bytes = y.to_bytes();
+    y_bytes.reverse();
+    Ok((
+        Bytes48::from_slice(&proof.to_compressed())?,
+        Bytes32(y_bytes),
+    ))
+}
+
+/// Proves the evaluation of `blob` at its challenge, as `compute_blob_kzg_proof`.
+pub fn compute_blob_kzg_proof<B: AsBlobRef>(
+    blob: &B,
+    commitment: &Bytes48,
+    kzg_settings: &KzgSettings,
+) -> Result<Bytes48, KzgError> {
+    let polynomial = blob.as_blob_ref().as_polynomial()?;
+    safe_g1_affine_from_bytes_native(commitment)?;
+    let z = compute_challenge(blob, commitment);
+    let (proof, _) = prove_in_lagrange_form(polynomial, &z, kzg_settings)?;
+    Bytes48::from_slice(&proof.to_compressed())
+}
+
+/// Commits to a polynomial in evaluation form with the G1 Lagrange points of a setup.
+pub(crate) fn commit_in_lagrange_form(
+    polynomial: &[Scalar],
+    g1_points: &[G1Affine],
+) -> Result<G1Affine, KzgError> {
+    if polynomial.len() != g1_points.len() {
+        return Err(TrustedSetupError::G1PointCount {
+            expected: polynomial.len(),
+            actual: g1_points.len(),
+        }
+        .into());
+    }
+    let commitment = g1_points
+        .iter()
+        .zip(polynomial)
+        .fold(G1Projective::identity(), |acc, (point, evaluation)| {
+            acc + point * evaluation
+        });
+    Ok(G1Affine::from(commitment))
+}
+
+/// Returns the proof of the evaluation of a polynomial in evaluation form at `z`, and the
+/// evaluation.
+pub(crate) fn prove_in_lagrange_form(
+    polynomial: Vec<Scalar>,
+    z: &Scalar,
+    kzg_settings: &KzgSettings,
+) -> Result<(G1Affine, Scalar), KzgError> {
+    let (quotient, y) =
+        Polynomial::new(polynomial).divide_by_linear(z, &kzg_settings.roots_of_unity)?;
+    let proof = commit_in_lagrange_form(quotient.evaluations(), &kzg_settings.g1_points)?;
+    Ok((proof, y))
+}
+
+/// An **insecure** trusted setup generated from a known secret `τ`, for testing setups of any size
+/// and failure paths that need proofs only the holder of `τ` can forge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsecureTrustedSetup {
+    pub secret: Scalar,
+    /// The roots of unity of the domain, in bit-reversed order.
+    pub roots_of_unity: Vec<Scalar>,
+    /// `[L_i(τ)]₁` for the Lagrange basis polynomial `L_i` of each bit-reversed root of unity.
+    pub g1_lagrange_points: Vec<G1Affine>,
+    /// `[τ^i]₁` for `i` below the domain size.
+    pub g1_monomial_points: Vec<G1Affine>,
+    /// `[τ^i]₂` for `i < NUM_G2_POINTS`.
+    pub g2_points: Vec<G2Affine>,
+}
+
+impl InsecureTrustedSetup {
+    /// Generates the setup of `num_g1_points` G1 points from `secret`, in the same order as the
+    /// setup embedded by `build.rs`. `num_g1_points` must be a power of two.
+    pub fn generate(secret: Scalar, num_g1_points: usize) -> Result<Self, KzgError> {
+        let roots_of_unity = compute_roots_of_unity(num_g1_points)?;
+        let vanishing = secret.pow_vartime(&[num_g1_points as u64, 0, 0, 0]) - Scalar::one();
+        let width = Scalar::from(num_g1_points as u64);
+
+        // The Lagrange basis polynomial of the root w is L(X) = w (X^n - 1) / (n (X - w)).
+        let g1_lagrange_points = roots_of_unity
+            .iter()
+            .map(|&root| {
+                let denominator = Option::<Scalar>::from((width * (secret - root)).invert())
+                    .ok_or(KzgError::Ceremony(CeremonyError::SecretIsRootOfUnity))?;
+                Ok(G1Affine::from(
+                    G1Affine::generator() * (root * vanishing * denominator),
+                ))
+            })
+            .collect::<Result<_, KzgError>>()?;
+
+        let g1_monomial_points = powers(secret, num_g1_points)
+            .map(|power| G1Affine::from(G1Affine::generator() * power))
+            .collect();
+        let g2_points = powers(secret, NUM_G2_POINTS)
+            .map(|power| G2Affine::from(G2Affine::generator() * power))
+            .collect();
+
+        Ok(Self {
+            secret,
+            roots_of_unity,
+            g1_lagrange_points,
+            g1_monomial_points,
+            g2_points,
+        })
+    }
+
+    /// Encodes the setup in the `trusted_setup.txt` format, including the monomial G1 points.
+    pub fn to_trusted_setup_txt(&self) -> String {
+        encode_trusted_setup(
+            &self.g1_lagrange_points,
+            &self.g2_points,
+            &self.g1_monomial_points,
+        )
+    }
+
+    /// Returns the setup as [`KzgSettings`].
+    pub fn to_kzg_settings(&self) -> KzgSettings {
+        KzgSettings {
+            roots_of_unity: self.roots_of_unity.clone().into(),
+            g1_points: self.g1_lagrange_points.clone().into(),
+            g2_points: self.g2_points.clone().into(),
+        }
+    }
+
+    /// Commits to a polynomial given by its evaluations over the bit-reversed roots of unity.
+    pub fn commit(&self, polynomial: &[Scalar]) -> Result<Bytes48, KzgError> {
+        let p_tau = self.evaluate(polynomial, self.secret)?;
+        Bytes48::from_slice(&G1Affine::from(G1Affine::generator() * p_tau).to_compressed())
+    }
+
+    /// Proves that `polynomial` evaluates to `y` at `z`, returning the proof for any claimed `y`.
+    ///
+    /// With the correct evaluation this is an honest proof; with any other `y` the proof is
+    /// well-formed but must be rejected by verification.
+    pub fn compute_kzg_proof_for(
+        &self,
+        polynomial: &[Scalar],
+        z: Scalar,
+        y: Scalar,
+    ) -> Result<Bytes48, KzgError> {
+        let p_tau = self.evaluate(polynomial, self.secret)?;
+        let inverse = Option::<Scalar>::from((self.secret - z).invert())
+            .ok_or(KzgError::Ceremony(CeremonyError::OpeningAtSecret))?;
+        Bytes48::from_slice(
+            &G1Affine::from(G1Affine::generator() * ((p_tau - y) * inverse)).to_compressed(),
+        )
+    }
+
+    /// Proves the evaluation of `polynomial` at `z`, returning the proof and the evaluation.
+    pub fn compute_kzg_proof(
+        &self,
+        polynomial: &[Scalar],
+        z: Scalar,
+    ) -> Result<(Bytes48, Scalar), KzgError> {
+        let y = self.evaluate(polynomial, z)?;
+        Ok((self.compute_kzg_proof_for(polynomial, z, y)?, y))
+    }
+
+    /// Computes the commitment to `blob` and its blob proof, as checked by
+    /// [`crate::KzgProof::verify_blob_kzg_proof`].
+    pub fn compute_blob_kzg_proof<B: AsBlobRef>(
+        &self,
+        blob: &B,
+    ) -> Result<(Bytes48, Bytes48), KzgError> {
+        let polynomial = blob.as_blob_ref().as_polynomial()?;
+        let commitment = self.commit(&polynomial)?;
+        let z = compute_challenge(blob, &commitment);
+        let (proof, _) = self.compute_kzg_proof(&polynomial, z)?;
+        Ok((commitment, proof))
+    }
+
+    /// Evaluates a polynomial given by its evaluations over the bit-reversed roots of unity.
+    fn evaluate(&self, polynomial: &[Scalar], z: Scalar) -> Result<Scalar, KzgError> {
+        Polynomial::new(polynomial.to_vec()).evaluate(&z, &self.roots_of_unity)
+    }
+}
+
+fn powers(base: Scalar, count: usize) -> impl Iterator<Item = Scalar> {
+    core::iter::successors(Some(Scalar::one()), move |power| Some(power * base)).take(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_minimal_kzg_settings, load_trusted_setup_from_str, KzgProof, Minimal};
+    use bls12_381::pairing;
+
+    #[test]
+    fn test_insecure_trusted_setup_matches_minimal() {
+        let setup = InsecureTrustedSetup::generate(Scalar::from(1337u64), 4).unwrap();
+        assert_eq!(setup.to_kzg_settings(), get_minimal_kzg_settings());
+    }
+
+    #[test]
+    fn test_insecure_trusted_setup_txt_roundtrip() {
+        let setup = InsecureTrustedSetup::generate(Scalar::from(42u64), 16).unwrap();
+        let settings = load_trusted_setup_from_str(&setup.to_trusted_setup_txt()).unwrap();
+        assert_eq!(settings, setup.to_kzg_settings());
+
+        // The monomial and Lagrange points commit to the same polynomial: X = Σ w_i L_i(X).
+        let lagrange_commitment = setup
+            .g1_lagrange_points
+            .iter()
+            .zip(&setup.roots_of_unity)
+            .fold(bls12_381::G1Projective::identity(), |acc, (point, root)| {
+                acc + point * root
+            });
+        assert_eq!(
+            G1Affine::from(lagrange_commitment),
+            setup.g1_monomial_points[1]
+        );
+        assert_eq!(
+            pairing(&setup.g1_monomial_points[1], &G2Affine::generator()),
+            pairing(&G1Affine::generator(), &setup.g2_points[1])
+        );
+    }
+
+    #[test]
+    fn test_insecure_trusted_setup_proofs() {
+        let setup = InsecureTrustedSetup::generate(Scalar::from(1337u64), 4).unwrap();
+        let settings = setup.to_kzg_settings();
+        let polynomial: Vec<Scalar> = (1..=4u64).map(Scalar::from).collect();
+        let commitment = setup.commit(&polynomial).unwrap();
+        let z = Scalar::from(5u64);
+        let (proof, y) = setup.compute_kzg_proof(&polynomial, z).unwrap();
+        let z_bytes = Bytes32::from_slice(&be_bytes(z)).unwrap();
+        let verify = |y: Scalar, proof: &Bytes48| {
+            let y_bytes = Bytes32::from_slice(&be_bytes(y)).unwrap();
+            KzgProof::verify_kzg_proof(&commitment, &z_bytes, &y_bytes, proof, &settings)
+                .unwrap()
+                .is_valid()
+        };
+        assert!(verify(y, &proof));
+        assert!(!verify(y + Scalar::one(), &proof));
+
+        // Only the holder of the secret can forge a proof of a wrong evaluation.
+        let wrong_y = y + Scalar::one();
+        let forged = setup
+            .compute_kzg_proof_for(&polynomial, z, wrong_y)
+            .unwrap();
+        assert!(verify(wrong_y, &forged));
+
+        let blob = Blob::<Minimal>::from_slice(&[0u8; crate::BYTES_PER_BLOB_MINIMAL]).unwrap();
+        let (commitment, proof) = setup.compute_blob_kzg_proof(&blob).unwrap();
+        assert!(
+            KzgProof::verify_blob_kzg_proof(&blob, &commitment, &proof, &settings)
+                .unwrap()
+                .is_valid()
+        );
+    }
+
+    fn be_bytes(scalar: Scalar) -> [u8; 32] {
+        let mut bytes = scalar.to_bytes();
+        bytes.reverse();
+        bytes
+    }
+}
@@ -0,0 +1,253 @@
+//! The consensus-spec test vectors in `tests/`, indexed by `build.rs`.
+//!
+//! Every `data.yaml` under `tests/` is embedded, in either the flat `<format>/<case>` layout of
+//! this repository or the `<format>/<suite>/<case>` layout of the spec releases (such as
+//! `verify_kzg_proof/kzg-mainnet/<case>`), so that a new release only needs to be copied in.
+//! [`test_vectors`] iterates over the parsed cases of one format, named by a [`TestFormat`]:
+//!
+//! ```ignore
+//! for (name, test) in test_vectors::<VerifyKzgProof>().valid() {
+//!     // ...
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use serde::Deserialize;
+
+use crate::{
+    test_utils::{
+        BlobBatchInput, BlobCommitmentInput, BlobInput, BlobPointInput, Cell, CellBatchInput,
+        CellRecoveryInput, Input, SingleBlobInput, Test,
+    },
+    Bytes32, Bytes48,
+};
+
+/// Every test vector in `tests/`, sorted by format and name.
+pub static TEST_VECTORS: &[TestVector] = include!(concat!(env!("OUT_DIR"), "/test_vectors.rs"));
+
+/// The `output` of a test vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpectedOutput {
+    True,
+    False,
+    /// The inputs must be rejected.
+    Null,
+    /// Any other output, such as the commitment of `blob_to_kzg_commitment`.
+    Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector {
+    /// The test format, such as `verify_kzg_proof`.
+    pub format: &'static str,
+    pub name: &'static str,
+    pub output: ExpectedOutput,
+    /// The contents of `data.yaml`.
+    pub data: &'static str,
+}
+
+impl TestVector {
+    pub fn parse<I, O>(&self) -> Result<Test<I, O>, serde_yaml::Error>
+    where
+        I: Deserialize<'static>,
+        O: Deserialize<'static>,
+    {
+        serde_yaml::from_str(self.data)
+    }
+}
+
+/// A consensus-spec test format, named after its directory, with the types of its input and
+/// output.
+pub trait TestFormat {
+    const FORMAT: &'static str;
+    type Input: Deserialize<'static>;
+    type Output: Deserialize<'static>;
+}
+
+macro_rules! test_formats {
+    ($($(#[$doc:meta])* $name:ident($format:literal, $input:ty, $output:ty);)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $name;
+
+            impl TestFormat for $name {
+                const FORMAT: &'static str = $format;
+                type Input = $input;
+                type Output = $output;
+            }
+        )*
+
+        /// The names of every format in this module.
+        pub const FORMATS: &[&str] = &[$($format),*];
+    };
+}
+
+test_formats! {
+    BlobToKzgCommitment("blob_to_kzg_commitment", SingleBlobInput<'static>, Bytes48);
+    /// The output is the proof and `y`.
+    ComputeKzgProof("compute_kzg_proof", BlobPointInput<'static>, (Bytes48, Bytes32));
+    ComputeBlobKzgProof("compute_blob_kzg_proof", BlobCommitmentInput<'static>, Bytes48);
+    /// Not a format of the spec releases: the Fiat-Shamir challenge of a blob.
+    ComputeChallenge("compute_challenge", BlobCommitmentInput<'static>, Bytes32);
+    VerifyKzgProof("verify_kzg_proof", Input<'static>, bool);
+    VerifyBlobKzgProof("verify_blob_kzg_proof", BlobInput<'static>, bool);
+    VerifyBlobKzgProofBatch("verify_blob_kzg_proof_batch", BlobBatchInput<'static>, bool);
+    /// EIP-7594.
+    ComputeCells("compute_cells", SingleBlobInput<'static>, Vec<Cell>);
+    /// EIP-7594. The output is the cells and their proofs.
+    ComputeCellsAndKzgProofs(
+        "compute_cells_and_kzg_proofs",
+        SingleBlobInput<'static>,
+        (Vec<Cell>, Vec<Bytes48>)
+    );
+    /// EIP-7594.
+    VerifyCellKzgProofBatch("verify_cell_kzg_proof_batch", CellBatchInput<'static>, bool);
+    /// EIP-7594. The output is all cells and their proofs.
+    RecoverCellsAndKzgProofs(
+        "recover_cells_and_kzg_proofs",
+        CellRecoveryInput<'static>,
+        (Vec<Cell>, Vec<Bytes48>)
+    );
+}
+
+/// Returns the test vectors of the format `F`.
+pub fn test_vectors<F: TestFormat>() -> TestVectors<F> {
+    TestVectors {
+        vectors: TEST_VECTORS.iter(),
+        outputs: &[
+            ExpectedOutput::True,
+            ExpectedOutput::False,
+            ExpectedOutput::Null,
+            ExpectedOutput::Value,
+        ],
+        _format: PhantomData,
+    }
+}
+
+/// An iterator over the name and parsed `data.yaml` of the test vectors of one format.
+///
+/// Panics on a vector that does not parse as a test of `F`.
+#[derive(Debug, Clone)]
+pub struct TestVectors<F> {
+    vectors: core::slice::Iter<'static, TestVector>,
+    outputs: &'static [ExpectedOutput],
+    _format: PhantomData<F>,
+}
+
+impl<F: TestFormat> TestVectors<F> {
+    /// Keeps the vectors with an output of `true`.
+    pub fn valid(self) -> Self {
+        self.with_outputs(&[ExpectedOutput::True])
+    }
+
+    /// Keeps the vectors with an output of `false` or `null`.
+    pub fn invalid(self) -> Self {
+        self.with_outputs(&[ExpectedOutput::False, ExpectedOutput::Null])
+    }
+
+    /// Keeps the vectors whose inputs must be rejected, with an output of `null`.
+    pub fn rejected(self) -> Self {
+        self.with_outputs(&[ExpectedOutput::Null])
+    }
+
+    pub fn with_outputs(self, outputs: &'static [ExpectedOutput]) -> Self {
+        Self { outputs, ..self }
+    }
+
+    /// Returns the vectors themselves rather than their parsed contents.
+    pub fn raw(self) -> impl Iterator<Item = &'static TestVector> {
+        let outputs = self.outputs;
+        self.vectors
+            .filter(move |vector| vector.format == F::FORMAT && outputs.contains(&vector.output))
+    }
+}
+
+impl<F: TestFormat> Iterator for TestVectors<F> {
+    type Item = (&'static str, Test<F::Input, F::Output>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outputs = self.outputs;
+        let vector = self
+            .vectors
+            .find(|vector| vector.format == F::FORMAT && outputs.contains(&vector.output))?;
+        let test = vector
+            .parse()
+            .unwrap_or_else(|e| panic!("{}/{}: {e}", vector.format, vector.name));
+        Some((vector.name, test))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::{Path, PathBuf};
+
+    /// Collects the paths of every `data.yaml` under `dir`.
+    fn find_data_files(dir: &Path, files: &mut Vec<PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap().flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            if !path.is_dir() || name.to_string_lossy().starts_with('.') || name == "target" {
+                continue;
+            }
+            let data = path.join("data.yaml");
+            if data.is_file() {
+                files.push(data);
+            } else {
+                find_data_files(&path, files);
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_covers_tests_dir() {
+        let tests_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+        let mut files = Vec::new();
+        find_data_files(&tests_dir, &mut files);
+        for file in &files {
+            let case = file.parent().unwrap();
+            let name = case.file_name().unwrap().to_str().unwrap();
+            assert!(
+                TEST_VECTORS.iter().any(|vector| vector.name == name
+                    && case.ancestors().any(|dir| dir.ends_with(vector.format))),
+                "{} is not indexed",
+                file.display()
+            );
+        }
+        for format in FORMATS {
+            if tests_dir.join(format).is_dir() {
+                assert!(
+                    TEST_VECTORS.iter().any(|vector| vector.format == *format),
+                    "no vectors for {format}"
+                );
+            }
+        }
+        assert!(TEST_VECTORS
+            .iter()
+            .all(|vector| FORMATS.contains(&vector.format)));
+        assert!(TEST_VECTORS
+            .windows(2)
+            .all(|pair| (pair[0].format, pair[0].name) < (pair[1].format, pair[1].name)));
+    }
+
+    #[test]
+    fn test_outputs_are_classified() {
+        for format in [
+            VerifyKzgProof::FORMAT,
+            VerifyBlobKzgProof::FORMAT,
+            VerifyBlobKzgProofBatch::FORMAT,
+        ] {
+            for vector in TEST_VECTORS.iter().filter(|vector| vector.format == format) {
+                let test: Test<serde_yaml::Value> = vector.parse().unwrap();
+                let expected = match test.get_output() {
+                    Some(true) => ExpectedOutput::True,
+                    Some(false) => ExpectedOutput::False,
+                    None => ExpectedOutput::Null,
+                };
+                assert_eq!(vector.output, expected, "{}", vector.name);
+            }
+        }
+    }
+}
@@ -1,5 +1,12 @@
-use crate::{types::KzgSettings, NUM_G1_POINTS, NUM_G2_POINTS, NUM_ROOTS_OF_UNITY};
+use crate::{
+    fft::bit_reverse_in_place,
+    types::{KzgSettings, KzgVerifierSettings},
+    InputError, KzgError, TrustedSetupError, BYTES_PER_G1_POINT, BYTES_PER_G2_POINT,
+    NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL, NUM_G1_POINTS, NUM_G2_POINTS, NUM_ROOTS_OF_UNITY,
+    SCALE2_ROOT_OF_UNITY,
+};
 
+use alloc::{format, string::String, vec::Vec};
 use bls12_381::{G1Affine, G2Affine, Scalar};
 use core::{mem::align_of, slice};
 use spin::Once;
@@ -25,67 +32,284 @@ macro_rules! include_bytes_align_as {
     }};
 }
 
+/// Returns the points in the `.bin` file at `$path`, relative to the crate root, as a `'static`
+/// slice of `$len` values of type `$ty`.
+macro_rules! include_points {
+    ($ty:ty, $path:literal, $len:expr) => {{
+        static POINTS: Once<&'static [$ty]> = Once::new();
+        POINTS.call_once(|| {
+            static ALIGNED_BYTES: &[u8] =
+                include_bytes_align_as!($ty, concat!(env!("CARGO_MANIFEST_DIR"), $path));
+            // The minimum alignment required is 4
+            assert!(ALIGNED_BYTES.as_ptr() as usize % align_of::<$ty>() == 0);
+            unsafe { slice::from_raw_parts::<$ty>(ALIGNED_BYTES.as_ptr() as *const $ty, $len) }
+        })
+    }};
+}
+
 pub fn get_roots_of_unity() -> &'static [Scalar] {
-    static ROOTS_OF_UNITY: Once<&'static [Scalar]> = Once::new();
-    ROOTS_OF_UNITY.call_once(|| {
-        static ALIGNED_BYTES: &[u8] = include_bytes_align_as!(
-            Scalar,
-            concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/assets/trusted_setup/roots_of_unity.bin"
-            )
-        );
-        // The minimum alignment required is 4
-        assert!(ALIGNED_BYTES.as_ptr() as usize % align_of::<Scalar>() == 0);
-        unsafe {
-            slice::from_raw_parts::<Scalar>(
-                ALIGNED_BYTES.as_ptr() as *const Scalar,
-                NUM_ROOTS_OF_UNITY,
-            )
-        }
-    })
+    include_points!(
+        Scalar,
+        "/assets/trusted_setup/roots_of_unity.bin",
+        NUM_ROOTS_OF_UNITY
+    )
 }
 
 pub fn get_g1_points() -> &'static [G1Affine] {
-    static G1_POINTS: Once<&'static [G1Affine]> = Once::new();
-    G1_POINTS.call_once(|| {
-        static ALIGNED_BYTES: &[u8] = include_bytes_align_as!(
-            G1Affine,
-            concat!(env!("CARGO_MANIFEST_DIR"), "/assets/trusted_setup/g1.bin")
-        );
-        // The minimum alignment required is 4
-        assert!(ALIGNED_BYTES.as_ptr() as usize % align_of::<G1Affine>() == 0);
-        unsafe {
-            slice::from_raw_parts::<G1Affine>(
-                ALIGNED_BYTES.as_ptr() as *const G1Affine,
-                NUM_G1_POINTS,
-            )
-        }
-    })
+    include_points!(G1Affine, "/assets/trusted_setup/g1.bin", NUM_G1_POINTS)
 }
 
 pub fn get_g2_points() -> &'static [G2Affine] {
-    static G2_POINTS: Once<&'static [G2Affine]> = Once::new();
-    G2_POINTS.call_once(|| {
-        static ALIGNED_BYTES: &[u8] = include_bytes_align_as!(
+    include_points!(G2Affine, "/assets/trusted_setup/g2.bin", NUM_G2_POINTS)
+}
+
+pub fn get_kzg_settings() -> KzgSettings {
+    KzgSettings {
+        roots_of_unity: get_roots_of_unity().into(),
+        g1_points: get_g1_points().into(),
+        g2_points: get_g2_points().into(),
+    }
+}
+
+/// Returns the parts of the embedded trusted setup needed to verify point and blob proofs,
+/// without the G1 points.
+pub fn get_kzg_verifier_settings() -> KzgVerifierSettings {
+    KzgVerifierSettings {
+        g2_tau: get_g2_points()[1],
+        roots_of_unity: Some(get_roots_of_unity().into()),
+    }
+}
+
+/// Returns the parts of the embedded trusted setup needed to verify point proofs with
+/// [`crate::KzgProof::verify_kzg_proof`], without the G1 points or the roots of unity.
+pub fn get_kzg_point_verifier_settings() -> KzgVerifierSettings {
+    KzgVerifierSettings {
+        g2_tau: get_g2_points()[1],
+        roots_of_unity: None,
+    }
+}
+
+/// Returns the trusted setup of the [`crate::Minimal`] preset, generated by `build.rs` from a
+/// publicly known secret. Only suitable for testing.
+pub fn get_minimal_kzg_settings() -> KzgSettings {
+    KzgSettings {
+        roots_of_unity: include_points!(
+            Scalar,
+            "/assets/trusted_setup/minimal/roots_of_unity.bin",
+            NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL
+        )
+        .into(),
+        g1_points: include_points!(
+            G1Affine,
+            "/assets/trusted_setup/minimal/g1.bin",
+            NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL
+        )
+        .into(),
+        g2_points: include_points!(
             G2Affine,
-            concat!(env!("CARGO_MANIFEST_DIR"), "/assets/trusted_setup/g2.bin")
+            "/assets/trusted_setup/minimal/g2.bin",
+            NUM_G2_POINTS
+        )
+        .into(),
+    }
+}
+
+/// Reads a trusted setup in its canonical encoding from the OpenVM input stream and checks that it
+/// hashes to `expected_digest`.
+///
+/// The host supplies the setup with `StdIn::write_bytes(&settings.to_canonical_bytes())`.
+/// `expected_digest` should be a constant of the guest program, so that the same program can be
+/// used with any setup while its proofs stay bound to a known one.
+#[cfg(target_os = "zkvm")]
+pub fn read_kzg_settings(expected_digest: &[u8; 32]) -> Result<KzgSettings, crate::KzgError> {
+    let bytes = openvm::io::read_vec();
+    KzgSettings::from_canonical_bytes(&bytes, expected_digest)
+}
+
+/// Reads verifier settings in their canonical encoding from the OpenVM input stream and checks
+/// that they hash to `expected_digest`. See [`read_kzg_settings`].
+///
+/// Only `[τ]₂` and optionally the roots of unity are read, so the G1 points are never loaded.
+#[cfg(target_os = "zkvm")]
+pub fn read_kzg_verifier_settings(
+    expected_digest: &[u8; 32],
+) -> Result<KzgVerifierSettings, crate::KzgError> {
+    let bytes = openvm::io::read_vec();
+    KzgVerifierSettings::from_canonical_bytes(&bytes, expected_digest)
+}
+
+/// Computes the `n`-th roots of unity in bit-reversed order, as embedded by `build.rs` for
+/// `n = 4096`. `n` must be a power of two.
+pub fn compute_roots_of_unity(n: usize) -> Result<Vec<Scalar>, KzgError> {
+    if !n.is_power_of_two() || n.trailing_zeros() as usize >= SCALE2_ROOT_OF_UNITY.len() {
+        return Err(KzgError::BadArgs(InputError::RootsOfUnityCount { n }));
+    }
+
+    let root = Scalar::from_raw(SCALE2_ROOT_OF_UNITY[n.trailing_zeros() as usize]);
+    let mut roots = Vec::with_capacity(n);
+    let mut current = Scalar::one();
+    for _ in 0..n {
+        roots.push(current);
+        current *= root;
+    }
+    bit_reverse_in_place(&mut roots);
+    Ok(roots)
+}
+
+/// Encodes a setup in the `trusted_setup.txt` format read by `build.rs` and
+/// [`load_trusted_setup_from_str`]: the point counts, the Lagrange G1 points in natural order, the
+/// G2 points and then, if any, the monomial G1 points.
+///
+/// `g1_lagrange_points` are in the bit-reversed order of [`KzgSettings::g1_points`].
+pub fn encode_trusted_setup(
+    g1_lagrange_points: &[G1Affine],
+    g2_points: &[G2Affine],
+    g1_monomial_points: &[G1Affine],
+) -> String {
+    let mut g1_lagrange_points = g1_lagrange_points.to_vec();
+    // The file stores the points in natural order; loading it bit-reverses them again.
+    bit_reverse_in_place(&mut g1_lagrange_points);
+
+    let mut contents = format!("{}\n{}\n", g1_lagrange_points.len(), g2_points.len());
+    let lines = g1_lagrange_points
+        .iter()
+        .map(|point| hex::encode(point.to_compressed()))
+        .chain(
+            g2_points
+                .iter()
+                .map(|point| hex::encode(point.to_compressed())),
+        )
+        .chain(
+            g1_monomial_points
+                .iter()
+                .map(|point| hex::encode(point.to_compressed())),
         );
-        // The minimum alignment required is 4
-        assert!(ALIGNED_BYTES.as_ptr() as usize % align_of::<G2Affine>() == 0);
-        unsafe {
-            slice::from_raw_parts::<G2Affine>(
-                ALIGNED_BYTES.as_ptr() as *const G2Affine,
-                NUM_G2_POINTS,
-            )
+    for line in lines {
+        contents.push_str(&line);
+        contents.push('\n');
+    }
+    contents
+}
+
+/// Parses a trusted setup in the `trusted_setup.txt` format: the number of G1 points, the number
+/// of G2 points, the compressed G1 points in Lagrange form and then the compressed G2 points in
+/// monomial form, one hex-encoded point per line. Any lines after the G2 points, such as the G1
+/// points in monomial form of newer setup files, are ignored.
+///
+/// The number of G1 points can be any power of two, so that test setups of other sizes (see
+/// `test_utils::InsecureTrustedSetup`) can be loaded; the mainnet setup has 4096.
+///
+/// The G1 points are stored in bit-reversed order, matching the setup embedded by `build.rs`.
+/// The returned settings own the parsed points.
+pub fn load_trusted_setup_from_str(contents: &str) -> Result<KzgSettings, KzgError> {
+    let mut lines = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let mut read_count = |name: &'static str| -> Result<usize, KzgError> {
+        lines
+            .next()
+            .and_then(|line| line.parse::<usize>().ok())
+            .ok_or(KzgError::InvalidTrustedSetup(
+                TrustedSetupError::MissingCount(name),
+            ))
+    };
+    let num_g1_points = read_count("G1 points")?;
+    let num_g2_points = read_count("G2 points")?;
+    if !num_g1_points.is_power_of_two() || num_g2_points < 2 {
+        return Err(TrustedSetupError::PointCounts {
+            num_g1_points,
+            num_g2_points,
         }
+        .into());
+    }
+
+    let mut g1_points = Vec::with_capacity(num_g1_points);
+    for line in lines.by_ref().take(num_g1_points) {
+        let bytes: [u8; BYTES_PER_G1_POINT] = decode_hex_point(line)?;
+        let point = Option::from(G1Affine::from_compressed(&bytes))
+            .ok_or(TrustedSetupError::InvalidG1Point)?;
+        g1_points.push(point);
+    }
+    let mut g2_points = Vec::with_capacity(num_g2_points);
+    for line in lines.by_ref().take(num_g2_points) {
+        let bytes: [u8; BYTES_PER_G2_POINT] = decode_hex_point(line)?;
+        let point = Option::from(G2Affine::from_compressed(&bytes))
+            .ok_or(TrustedSetupError::InvalidG2Point)?;
+        g2_points.push(point);
+    }
+    if g1_points.len() != num_g1_points || g2_points.len() != num_g2_points {
+        return Err(TrustedSetupError::MissingPoints.into());
+    }
+
+    bit_reverse_in_place(&mut g1_points);
+
+    let roots_of_unity = if num_g1_points == NUM_ROOTS_OF_UNITY {
+        get_roots_of_unity().into()
+    } else {
+        compute_roots_of_unity(num_g1_points)?.into()
+    };
+
+    Ok(KzgSettings {
+        roots_of_unity,
+        g1_points: g1_points.into(),
+        g2_points: g2_points.into(),
     })
 }
 
-pub fn get_kzg_settings() -> KzgSettings {
-    KzgSettings {
-        roots_of_unity: get_roots_of_unity(),
-        g1_points: get_g1_points(),
-        g2_points: get_g2_points(),
+/// Reads and parses a trusted setup file. See [`load_trusted_setup_from_str`].
+#[cfg(feature = "std")]
+pub fn load_trusted_setup_from_path(
+    path: impl AsRef<std::path::Path>,
+) -> Result<KzgSettings, KzgError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| KzgError::InvalidTrustedSetup(TrustedSetupError::Read(e.kind())))?;
+    load_trusted_setup_from_str(&contents)
+}
+
+fn decode_hex_point<const N: usize>(line: &str) -> Result<[u8; N], KzgError> {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(line.strip_prefix("0x").unwrap_or(line), &mut bytes)
+        .map_err(KzgError::InvalidHexFormat)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_trusted_setup_matches_embedded() {
+        let settings = load_trusted_setup_from_str(include_str!("trusted_setup.txt")).unwrap();
+        assert_eq!(settings, get_kzg_settings());
+    }
+
+    #[test]
+    fn test_compute_roots_of_unity_matches_embedded() {
+        assert_eq!(
+            compute_roots_of_unity(NUM_ROOTS_OF_UNITY).unwrap(),
+            get_roots_of_unity()
+        );
+        assert_eq!(
+            compute_roots_of_unity(NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL).unwrap(),
+            get_minimal_kzg_settings().roots_of_unity[..]
+        );
+        assert!(compute_roots_of_unity(3).is_err());
+    }
+
+    #[test]
+    fn test_minimal_trusted_setup_digest() {
+        let settings = get_minimal_kzg_settings();
+        assert_eq!(settings.digest(), crate::MINIMAL_TRUSTED_SETUP_DIGEST);
+    }
+
+    #[test]
+    fn test_minimal_setup_matches_insecure_setup() {
+        let setup = crate::test_utils::InsecureTrustedSetup::generate(
+            Scalar::from(1337u64),
+            NUM_FIELD_ELEMENTS_PER_BLOB_MINIMAL,
+        )
+        .unwrap();
+        assert_eq!(get_minimal_kzg_settings(), setup.to_kzg_settings());
     }
 }
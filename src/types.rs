@@ -1,13 +1,25 @@
-use alloc::sync::Arc;
-use core::hash::{Hash, Hasher};
+use alloc::{borrow::Cow, sync::Arc, vec::Vec};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 
 use bls12_381::{G1Affine, G2Affine, Scalar};
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Serialize,
+};
 use serde_big_array::BigArray;
+use sha2::{Digest, Sha256};
 use spin::Once;
 
+use crate::codec::{deserialize_hex_or, serialize_hex_or};
 use crate::get_kzg_settings;
-use crate::{Bytes32, Bytes48, KzgError};
+use crate::{
+    Blob, Bytes32, Bytes48, KzgError, KzgPreset, Mainnet, TrustedSetupError,
+    BYTES_PER_FIELD_ELEMENT,
+};
 
 /// Inputs to pass to the VM for KZG proof verification
 /// Excludes `KzgSettings`, which is read from disk by the VM
@@ -19,13 +31,85 @@ pub struct KzgInputs {
     pub proof_bytes: Bytes48,
 }
 
+/// Inputs to pass to the VM for blob KZG proof verification with
+/// [`crate::KzgProof::verify_blob_kzg_proof`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct KzgBlobInputs<P: KzgPreset = Mainnet> {
+    pub blob: Blob<P>,
+    pub commitment_bytes: Bytes48,
+    pub proof_bytes: Bytes48,
+}
+
+/// Inputs to pass to the VM for batch verification with
+/// [`crate::KzgProof::verify_blob_kzg_proof_batch`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct KzgBlobBatchInputs<P: KzgPreset = Mainnet> {
+    pub blobs: Vec<Blob<P>>,
+    pub commitments_bytes: Vec<Bytes48>,
+    pub proofs_bytes: Vec<Bytes48>,
+}
+
+impl<P: KzgPreset> Serialize for Blob<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // A single run of bytes instead of one element per byte
+        serialize_hex_or(self.as_slice(), serializer, |serializer| {
+            serializer.serialize_bytes(self.as_slice())
+        })
+    }
+}
+
+impl<'de, P: KzgPreset> Deserialize<'de> for Blob<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_hex_or(deserializer, |deserializer| {
+            deserializer.deserialize_bytes(BlobVisitor(PhantomData))
+        })
+    }
+}
+
+struct BlobVisitor<P>(PhantomData<P>);
+
+impl<'de, P: KzgPreset> Visitor<'de> for BlobVisitor<P> {
+    type Value = Blob<P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes", P::BYTES_PER_BLOB)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Blob::from_slice(bytes).map_err(E::custom)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Blob::try_from(bytes).map_err(E::custom)
+    }
+
+    // Formats without a native byte string encode bytes as a sequence.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(P::BYTES_PER_BLOB);
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_byte_buf(bytes)
+    }
+}
+
 impl Serialize for Bytes48 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        // Get the underlying array and serialize it
-        BigArray::serialize(&self.0, serializer)
+        serialize_hex_or(&self.0, serializer, |serializer| {
+            // Get the underlying array and serialize it
+            BigArray::serialize(&self.0, serializer)
+        })
     }
 }
 
@@ -34,10 +118,11 @@ impl<'de> Deserialize<'de> for Bytes48 {
     where
         D: serde::Deserializer<'de>,
     {
-        // Deserialize into a temporary array
-        let arr = <[u8; 48] as BigArray<u8>>::deserialize(deserializer)?;
-        // Convert the array to Bytes48
-        Ok(Bytes48::from_slice(&arr).unwrap())
+        deserialize_hex_or(deserializer, |deserializer| {
+            // Deserialize into a temporary array
+            let arr = <[u8; 48] as BigArray<u8>>::deserialize(deserializer)?;
+            Ok(Bytes48(arr))
+        })
     }
 }
 
@@ -46,7 +131,9 @@ impl Serialize for Bytes32 {
     where
         S: serde::Serializer,
     {
-        Serialize::serialize(&self.0, serializer)
+        serialize_hex_or(&self.0, serializer, |serializer| {
+            Serialize::serialize(&self.0, serializer)
+        })
     }
 }
 
@@ -55,16 +142,119 @@ impl<'de> Deserialize<'de> for Bytes32 {
     where
         D: serde::Deserializer<'de>,
     {
-        Ok(Bytes32::from_slice(&<[u8; 32] as BigArray<u8>>::deserialize(deserializer)?).unwrap())
+        deserialize_hex_or(deserializer, |deserializer| {
+            Ok(Bytes32(<[u8; 32] as BigArray<u8>>::deserialize(
+                deserializer,
+            )?))
+        })
     }
 }
 
+/// A trusted setup. The embedded setups borrow their points from the program, while setups that
+/// are parsed or generated at runtime own theirs and free them when dropped.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(C, align(4))]
 pub struct KzgSettings {
-    pub roots_of_unity: &'static [Scalar],
-    pub g1_points: &'static [G1Affine],
-    pub g2_points: &'static [G2Affine],
+    pub roots_of_unity: Cow<'static, [Scalar]>,
+    pub g1_points: Cow<'static, [G1Affine]>,
+    pub g2_points: Cow<'static, [G2Affine]>,
+}
+
+/// The parts of a trusted setup that verification reads. Implemented by the full
+/// [`KzgSettings`] and by the slimmer [`KzgVerifierSettings`].
+pub trait VerifierSetup {
+    /// Returns `[τ]₂`, the second G2 point of the setup.
+    fn g2_tau(&self) -> G2Affine;
+
+    /// Returns the bit-reversed roots of unity of the blob domain, if available. They are only
+    /// needed for blob verification.
+    fn roots_of_unity(&self) -> Option<&[Scalar]>;
+}
+
+impl VerifierSetup for KzgSettings {
+    fn g2_tau(&self) -> G2Affine {
+        self.g2_points[1]
+    }
+
+    fn roots_of_unity(&self) -> Option<&[Scalar]> {
+        Some(&self.roots_of_unity)
+    }
+}
+
+/// Only what verification needs from a trusted setup: `[τ]₂` and, for blob verification, the
+/// roots of unity. The G2 generator is a constant of the curve and is not stored.
+///
+/// Guests that only verify proofs should use these instead of [`KzgSettings`], so that the
+/// table of G1 points is not linked into the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KzgVerifierSettings {
+    pub g2_tau: G2Affine,
+    pub roots_of_unity: Option<Cow<'static, [Scalar]>>,
+}
+
+impl VerifierSetup for KzgVerifierSettings {
+    fn g2_tau(&self) -> G2Affine {
+        self.g2_tau
+    }
+
+    fn roots_of_unity(&self) -> Option<&[Scalar]> {
+        self.roots_of_unity.as_deref()
+    }
+}
+
+impl KzgVerifierSettings {
+    /// Returns the canonical encoding of these settings, which is that of a [`KzgSettings`] with
+    /// no G1 points, the G2 points `[1]₂` and `[τ]₂`, and the roots of unity if present.
+    ///
+    /// This is the format read by `read_kzg_verifier_settings` in a guest.
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_canonical_encoding(
+            &[],
+            &[G2Affine::generator(), self.g2_tau],
+            self.roots_of_unity.as_deref().unwrap_or_default(),
+            |chunk| bytes.extend_from_slice(chunk),
+        );
+        bytes
+    }
+
+    /// Parses settings from their canonical encoding after checking that the encoding hashes to
+    /// `expected_digest`. See [`KzgSettings::from_canonical_bytes`].
+    pub fn from_canonical_bytes(
+        bytes: &[u8],
+        expected_digest: &[u8; 32],
+    ) -> Result<Self, KzgError> {
+        let (g1_points, g2_points, roots_of_unity) =
+            read_canonical_encoding(bytes, expected_digest)?;
+        if !g1_points.is_empty() || g2_points.len() != 2 {
+            return Err(TrustedSetupError::VerifierSettingsShape.into());
+        }
+        Ok(Self {
+            g2_tau: g2_points[1],
+            roots_of_unity: (!roots_of_unity.is_empty()).then(|| roots_of_unity.into()),
+        })
+    }
+
+    /// Returns the SHA-256 digest of [`Self::to_canonical_bytes`].
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        write_canonical_encoding(
+            &[],
+            &[G2Affine::generator(), self.g2_tau],
+            self.roots_of_unity.as_deref().unwrap_or_default(),
+            |chunk| hasher.update(chunk),
+        );
+        hasher.finalize().into()
+    }
+}
+
+impl From<&KzgSettings> for KzgVerifierSettings {
+    fn from(settings: &KzgSettings) -> Self {
+        Self {
+            g2_tau: settings.g2_points[1],
+            roots_of_unity: Some(settings.roots_of_unity.clone()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default, Eq)]
@@ -74,23 +264,33 @@ pub enum EnvKzgSettings {
     Custom(Arc<KzgSettings>),
 }
 
+impl Hash for KzgSettings {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashing every point would be as slow as `digest`, so only hash enough of the setup to
+        // tell different setups apart. This is consistent with the content-based `Eq`.
+        self.roots_of_unity.len().hash(state);
+        self.g1_points.len().hash(state);
+        self.g2_points.len().hash(state);
+        if let Some(tau_g2) = self.g2_points.get(1) {
+            tau_g2.to_compressed().hash(state);
+        }
+    }
+}
+
+/// Compares the setups by content, so a custom setup equal to the embedded one equals `Default`.
 impl PartialEq for EnvKzgSettings {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Default, Self::Default) => true,
-            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
-            _ => false,
+            (Self::Custom(a), Self::Custom(b)) if Arc::ptr_eq(a, b) => true,
+            _ => self.get() == other.get(),
         }
     }
 }
 
 impl Hash for EnvKzgSettings {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        core::mem::discriminant(self).hash(state);
-        match self {
-            Self::Default => {}
-            Self::Custom(settings) => Arc::as_ptr(settings).hash(state),
-        }
+        self.get().hash(state);
     }
 }
 
@@ -109,8 +309,285 @@ impl EnvKzgSettings {
     }
 }
 
+#[cfg(feature = "std")]
+impl EnvKzgSettings {
+    /// Selects the trusted setup named by the [`crate::KZG_TRUSTED_SETUP_ENV_VAR`] environment
+    /// variable, falling back to the embedded setup when it is unset or empty.
+    ///
+    /// The variable is read and the setup parsed only once per process; later calls return the
+    /// cached result, including a cached error.
+    pub fn from_env() -> Result<Self, KzgError> {
+        static FROM_ENV: Once<Result<EnvKzgSettings, KzgError>> = Once::new();
+        FROM_ENV
+            .call_once(
+                || match std::env::var_os(crate::KZG_TRUSTED_SETUP_ENV_VAR) {
+                    Some(path) if !path.is_empty() => Self::from_path(path),
+                    _ => Ok(Self::Default),
+                },
+            )
+            .clone()
+    }
+
+    /// Loads a trusted setup in the `trusted_setup.txt` format from `path`.
+    ///
+    /// Setups are cached by canonical path, so that each file is parsed once per process: loading
+    /// the same file again returns the cached settings, even if the file changed.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Result<Self, KzgError> {
+        use alloc::collections::BTreeMap;
+        use std::{
+            path::PathBuf,
+            sync::{Mutex, PoisonError},
+        };
+
+        static FROM_PATH: Mutex<BTreeMap<PathBuf, Arc<KzgSettings>>> = Mutex::new(BTreeMap::new());
+        let path = std::fs::canonicalize(path)
+            .map_err(|e| KzgError::InvalidTrustedSetup(TrustedSetupError::Read(e.kind())))?;
+        // Holding the lock while parsing keeps concurrent loads of one path from parsing twice.
+        let mut cache = FROM_PATH.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(settings) = cache.get(&path) {
+            return Ok(Self::Custom(settings.clone()));
+        }
+        let settings = Arc::new(crate::load_trusted_setup_from_path(&path)?);
+        cache.insert(path, settings.clone());
+        Ok(Self::Custom(settings))
+    }
+}
+
 impl KzgSettings {
     pub fn load_trusted_setup_file() -> Result<Self, KzgError> {
         Ok(get_kzg_settings())
     }
+
+    /// Returns the canonical encoding of this setup: the numbers of G1 points, G2 points and
+    /// roots of unity as big-endian `u32`s, then every G1 point and every G2 point in uncompressed
+    /// form, then every root of unity as 32 big-endian bytes.
+    ///
+    /// This is the format read by `read_kzg_settings` in a guest.
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_canonical_encoding(
+            &self.g1_points,
+            &self.g2_points,
+            &self.roots_of_unity,
+            |chunk| bytes.extend_from_slice(chunk),
+        );
+        bytes
+    }
+
+    /// Parses a setup from its canonical encoding after checking that the encoding hashes to
+    /// `expected_digest`.
+    ///
+    /// The points are only checked to have canonical coordinates, not to be on the curve or in
+    /// the right subgroup, which is sound because the digest pins down a known good setup.
+    pub fn from_canonical_bytes(
+        bytes: &[u8],
+        expected_digest: &[u8; 32],
+    ) -> Result<Self, KzgError> {
+        let (g1_points, g2_points, roots_of_unity) =
+            read_canonical_encoding(bytes, expected_digest)?;
+        Ok(Self {
+            roots_of_unity: roots_of_unity.into(),
+            g1_points: g1_points.into(),
+            g2_points: g2_points.into(),
+        })
+    }
+
+    /// Returns the SHA-256 digest of [`Self::to_canonical_bytes`].
+    ///
+    /// The embedded mainnet setup has digest [`crate::MAINNET_TRUSTED_SETUP_DIGEST`].
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        write_canonical_encoding(
+            &self.g1_points,
+            &self.g2_points,
+            &self.roots_of_unity,
+            |chunk| hasher.update(chunk),
+        );
+        hasher.finalize().into()
+    }
+
+    /// Panics unless [`Self::digest`] equals `expected`.
+    ///
+    /// Use this in a guest to bind the proof to a specific trusted setup.
+    pub fn assert_digest(&self, expected: &[u8; 32]) {
+        assert_eq!(&self.digest(), expected, "trusted setup digest mismatch");
+    }
+
+    /// Reveals [`Self::digest`] as the first 32 bytes of the guest's public values, so that
+    /// verifiers of the proof learn which trusted setup the guest used.
+    #[cfg(target_os = "zkvm")]
+    pub fn reveal_digest(&self) -> [u8; 32] {
+        let digest = self.digest();
+        openvm::io::reveal_bytes32(digest);
+        digest
+    }
+}
+
+const BYTES_PER_UNCOMPRESSED_G1_POINT: usize = 96;
+const BYTES_PER_UNCOMPRESSED_G2_POINT: usize = 192;
+
+fn write_canonical_encoding(
+    g1_points: &[G1Affine],
+    g2_points: &[G2Affine],
+    roots_of_unity: &[Scalar],
+    mut write: impl FnMut(&[u8]),
+) {
+    write(&(g1_points.len() as u32).to_be_bytes());
+    write(&(g2_points.len() as u32).to_be_bytes());
+    write(&(roots_of_unity.len() as u32).to_be_bytes());
+    for point in g1_points {
+        write(&point.to_uncompressed());
+    }
+    for point in g2_points {
+        write(&point.to_uncompressed());
+    }
+    for root in roots_of_unity {
+        let mut bytes = root.to_bytes();
+        bytes.reverse();
+        write(&bytes);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn read_canonical_encoding(
+    bytes: &[u8],
+    expected_digest: &[u8; 32],
+) -> Result<(Vec<G1Affine>, Vec<G2Affine>, Vec<Scalar>), KzgError> {
+    let digest: [u8; 32] = Sha256::digest(bytes).into();
+    if &digest != expected_digest {
+        return Err(TrustedSetupError::DigestMismatch.into());
+    }
+
+    let (header, mut rest) = bytes
+        .split_at_checked(12)
+        .ok_or(KzgError::InvalidBytesLength {
+            expected: 12,
+            actual: bytes.len(),
+        })?;
+    let count =
+        |i: usize| u32::from_be_bytes(header[4 * i..4 * i + 4].try_into().unwrap()) as usize;
+    let (num_g1_points, num_g2_points, num_roots_of_unity) = (count(0), count(1), count(2));
+    let expected_len = num_g1_points * BYTES_PER_UNCOMPRESSED_G1_POINT
+        + num_g2_points * BYTES_PER_UNCOMPRESSED_G2_POINT
+        + num_roots_of_unity * BYTES_PER_FIELD_ELEMENT;
+    if rest.len() != expected_len {
+        return Err(KzgError::InvalidBytesLength {
+            expected: expected_len,
+            actual: rest.len(),
+        });
+    }
+
+    let mut take = |len: usize| {
+        let (chunk, remaining) = rest.split_at(len);
+        rest = remaining;
+        chunk
+    };
+    let mut g1_points = Vec::with_capacity(num_g1_points);
+    for _ in 0..num_g1_points {
+        let bytes = take(BYTES_PER_UNCOMPRESSED_G1_POINT).try_into().unwrap();
+        let point = Option::from(G1Affine::from_uncompressed_unchecked(bytes))
+            .ok_or_else(|| KzgError::InvalidTrustedSetup(TrustedSetupError::InvalidG1Point))?;
+        g1_points.push(point);
+    }
+    let mut g2_points = Vec::with_capacity(num_g2_points);
+    for _ in 0..num_g2_points {
+        let bytes = take(BYTES_PER_UNCOMPRESSED_G2_POINT).try_into().unwrap();
+        let point = Option::from(G2Affine::from_uncompressed_unchecked(bytes))
+            .ok_or_else(|| KzgError::InvalidTrustedSetup(TrustedSetupError::InvalidG2Point))?;
+        g2_points.push(point);
+    }
+    let mut roots_of_unity = Vec::with_capacity(num_roots_of_unity);
+    for _ in 0..num_roots_of_unity {
+        let mut bytes: [u8; BYTES_PER_FIELD_ELEMENT] =
+            take(BYTES_PER_FIELD_ELEMENT).try_into().unwrap();
+        bytes.reverse();
+        let root = Option::from(Scalar::from_bytes(&bytes))
+            .ok_or_else(|| KzgError::InvalidTrustedSetup(TrustedSetupError::InvalidRootOfUnity))?;
+        roots_of_unity.push(root);
+    }
+
+    Ok((g1_points, g2_points, roots_of_unity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_kzg_settings_from_path() {
+        let settings = EnvKzgSettings::from_path(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/trusted_setup.txt"
+        ))
+        .unwrap();
+        assert_eq!(settings.get(), EnvKzgSettings::Default.get());
+        assert!(EnvKzgSettings::from_path("does/not/exist.txt").is_err());
+
+        // Loading the same file again, under another spelling, returns the cached setup.
+        let again = EnvKzgSettings::from_path(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/../src/trusted_setup.txt"
+        ))
+        .unwrap();
+        let (EnvKzgSettings::Custom(a), EnvKzgSettings::Custom(b)) = (&settings, &again) else {
+            panic!("expected custom settings");
+        };
+        assert!(Arc::ptr_eq(a, b));
+    }
+
+    #[test]
+    fn test_mainnet_digest() {
+        let settings = KzgSettings::load_trusted_setup_file().unwrap();
+        assert_eq!(settings.digest(), crate::MAINNET_TRUSTED_SETUP_DIGEST);
+        settings.assert_digest(&crate::MAINNET_TRUSTED_SETUP_DIGEST);
+    }
+
+    #[test]
+    fn test_blob_inputs_serde() {
+        use crate::{Minimal, BYTES_PER_BLOB_MINIMAL};
+
+        let blob = Blob::<Minimal>::from_slice(&[7u8; BYTES_PER_BLOB_MINIMAL]).unwrap();
+        let inputs = KzgBlobBatchInputs {
+            blobs: alloc::vec![blob.clone(), blob],
+            commitments_bytes: alloc::vec![Bytes48::from_slice(&[1; 48]).unwrap(); 2],
+            proofs_bytes: alloc::vec![Bytes48::from_slice(&[2; 48]).unwrap(); 2],
+        };
+
+        let yaml = serde_yaml::to_string(&inputs).unwrap();
+        let decoded: KzgBlobBatchInputs<Minimal> = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(decoded.blobs[1], inputs.blobs[1]);
+        assert!(serde_yaml::from_str::<KzgBlobBatchInputs>(&yaml).is_err());
+
+        // The blob is a single run of bytes in the OpenVM input stream, not one word per byte.
+        let words = openvm::serde::to_vec(&inputs.blobs[0]).unwrap();
+        assert!(words.len() < BYTES_PER_BLOB_MINIMAL / 2);
+        let decoded: Blob<Minimal> = openvm::serde::from_slice(&words).unwrap();
+        assert_eq!(decoded, inputs.blobs[0]);
+    }
+
+    #[test]
+    fn test_canonical_bytes_roundtrip() {
+        let settings = crate::get_minimal_kzg_settings();
+        let bytes = settings.to_canonical_bytes();
+        let parsed = KzgSettings::from_canonical_bytes(&bytes, &settings.digest()).unwrap();
+        assert_eq!(parsed, settings);
+        assert!(KzgSettings::from_canonical_bytes(&bytes, &[0u8; 32]).is_err());
+
+        let mut truncated = bytes.clone();
+        truncated.pop();
+        let digest = Sha256::digest(&truncated).into();
+        assert!(KzgSettings::from_canonical_bytes(&truncated, &digest).is_err());
+
+        let verifier_settings = crate::get_kzg_verifier_settings();
+        let bytes = verifier_settings.to_canonical_bytes();
+        let parsed =
+            KzgVerifierSettings::from_canonical_bytes(&bytes, &verifier_settings.digest()).unwrap();
+        assert_eq!(parsed, verifier_settings);
+
+        let point_settings = crate::get_kzg_point_verifier_settings();
+        let bytes = point_settings.to_canonical_bytes();
+        let parsed =
+            KzgVerifierSettings::from_canonical_bytes(&bytes, &point_settings.digest()).unwrap();
+        assert_eq!(parsed, point_settings);
+    }
 }
@@ -2,78 +2,153 @@ use std::path::PathBuf;
 
 use openvm_build::{GuestOptions, TargetFilter};
 use openvm_circuit::openvm_stark_sdk::config::setup_tracing;
-use openvm_kzg::test_files::{
-    ONLY_INVALID_KZG_PROOF_TESTS, ONLY_VALID_KZG_PROOF_TESTS, SINGLE_VALID_KZG_PROOF_TEST,
-};
 use openvm_kzg::{
+    generator::VectorGenerator,
     test_utils::{Input, Test},
-    KzgInputs,
+    test_vectors::{test_vectors, VerifyKzgProof},
+    KzgInputs, Mainnet,
 };
 use openvm_sdk::{
     config::{AppConfig, SdkVmConfig},
     Sdk, StdIn,
 };
-use serde_yaml::from_str;
 
 #[test]
 fn test_single_valid_verify_kzg() {
-    let (_, data) = SINGLE_VALID_KZG_PROOF_TEST[0];
-    run_test_from_yaml_str(data);
+    let (_, test) = test_vectors::<VerifyKzgProof>().valid().next().unwrap();
+    run_test(&test);
 }
 
 #[test]
 fn test_multiple_valid_verify_kzg() {
-    for (test_file, data) in ONLY_VALID_KZG_PROOF_TESTS {
+    for (test_file, test) in test_vectors::<VerifyKzgProof>().valid() {
         println!("Running test: {}", test_file);
-        run_test_from_yaml_str(data);
+        run_test(&test);
     }
 }
 
 #[test]
 fn test_single_invalid_verify_kzg() {
-    let (test_file, data) = ONLY_INVALID_KZG_PROOF_TESTS[0];
-    let result = std::panic::catch_unwind(|| run_test_from_yaml_str(data));
+    let (test_file, test) = test_vectors::<VerifyKzgProof>().invalid().next().unwrap();
+    let result = std::panic::catch_unwind(|| run_test(&test));
     assert!(result.is_err(), "Test {} should have panicked", test_file);
 }
 
+#[test]
+fn test_self_check_verify_kzg() {
+    // On the host both backends decode points natively, so the malformed points of the generator
+    // are checked in the guest as well as the spec vectors.
+    let generated: Vec<_> = VectorGenerator::<Mainnet>::new(0)
+        .verify_kzg_proof_cases(1)
+        .unwrap()
+        .into_iter()
+        .filter(|case| {
+            case.name.contains("_case_invalid_commitment_")
+                || case.name.contains("_case_invalid_proof_")
+        })
+        .collect();
+
+    let mut names = Vec::new();
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    let mut push = |name: &str, test: &Test<Input>| {
+        // Inputs of the wrong length cannot be passed to the guest.
+        if let Some(input) = try_inputs_from_test(test) {
+            names.push(name.to_string());
+            inputs.push(input);
+            outputs.push(test.get_output());
+        }
+    };
+    for (test_file, test) in test_vectors::<VerifyKzgProof>() {
+        push(test_file, &test);
+    }
+    for case in &generated {
+        push(&case.name, &serde_yaml::from_str(&case.data).unwrap());
+    }
+
+    // The guest also verifies with the native backend and panics if the backends disagree.
+    let verdicts = execute_self_check(&inputs);
+    for ((name, verdict), output) in names.iter().zip(verdicts).zip(outputs) {
+        assert_eq!(verdict, output, "{name}");
+    }
+}
+
 #[ignore = "takes too long"]
 #[test]
 fn test_multiple_invalid_verify_kzg() {
-    for (test_file, data) in ONLY_INVALID_KZG_PROOF_TESTS {
+    for (test_file, test) in test_vectors::<VerifyKzgProof>().invalid() {
         println!("Running test: {}", test_file);
-        let result = std::panic::catch_unwind(|| run_test_from_yaml_str(data));
+        let result = std::panic::catch_unwind(|| run_test(&test));
         assert!(result.is_err(), "Test {} should have panicked", test_file);
     }
 }
 
-pub fn run_test_from_yaml_str(data: &str) {
-    let test: Test<Input> = from_str(data).unwrap();
-    let (Ok(commitment), Ok(z), Ok(y), Ok(proof)) = (
-        test.input.get_commitment(),
-        test.input.get_z(),
-        test.input.get_y(),
-        test.input.get_proof(),
-    ) else {
-        panic!("Invalid test inputs");
-    };
+pub fn run_test(test: &Test<Input>) {
+    run_guest_program(inputs_from_test(test), &[]);
+}
 
-    let input = KzgInputs {
-        commitment_bytes: commitment,
-        z_bytes: z,
-        y_bytes: y,
-        proof_bytes: proof,
-    };
+pub fn inputs_from_test(test: &Test<Input>) -> KzgInputs {
+    try_inputs_from_test(test).expect("Invalid test inputs")
+}
+
+/// Returns the inputs of the test, or `None` if any of them has the wrong length.
+pub fn try_inputs_from_test(test: &Test<Input>) -> Option<KzgInputs> {
+    Some(KzgInputs {
+        commitment_bytes: test.input.get_commitment().ok()?,
+        z_bytes: test.input.get_z().ok()?,
+        y_bytes: test.input.get_y().ok()?,
+        proof_bytes: test.input.get_proof().ok()?,
+    })
+}
+
+/// Builds the guest program with the given features and proves its execution on `input`.
+pub fn run_guest_program(input: KzgInputs, features: &[&str]) {
+    let sdk = guest_sdk();
+    let (guest_opts, pkg_dir, target_filter) = guest_build_options(features);
+    let elf = sdk
+        .build(guest_opts, &pkg_dir, &target_filter, None)
+        .unwrap();
 
-    run_guest_program(input);
+    let mut io = StdIn::default();
+    io.write(&input);
+
+    sdk.app_prover(elf).unwrap().prove(io).unwrap();
+}
+
+/// Builds the guest program with `self-check` and executes it on each input, returning the
+/// verdicts it reveals in the terms of the spec tests.
+pub fn execute_self_check(inputs: &[KzgInputs]) -> Vec<Option<bool>> {
+    let sdk = guest_sdk();
+    let (guest_opts, pkg_dir, target_filter) = guest_build_options(&["self-check"]);
+    let elf = sdk
+        .build(guest_opts, &pkg_dir, &target_filter, None)
+        .unwrap();
+
+    inputs
+        .iter()
+        .map(|input| {
+            let mut io = StdIn::default();
+            io.write(input);
+            let public_values = sdk.execute(elf.clone(), io).unwrap();
+            match u32::from_le_bytes(public_values[..4].try_into().unwrap()) {
+                0 => Some(false),
+                1 => Some(true),
+                2 => None,
+                verdict => panic!("unexpected verdict {verdict}"),
+            }
+        })
+        .collect()
 }
 
-pub fn run_guest_program(input: KzgInputs) {
+fn guest_sdk() -> Sdk {
     setup_tracing();
     let app_config: AppConfig<SdkVmConfig> =
         toml::from_str(include_str!("programs/verify_kzg/openvm.toml")).unwrap();
-    let sdk = Sdk::new(app_config).unwrap();
+    Sdk::new(app_config).unwrap()
+}
 
-    let guest_opts = GuestOptions::default();
+fn guest_build_options(features: &[&str]) -> (GuestOptions, PathBuf, Option<TargetFilter>) {
+    let guest_opts = GuestOptions::default().with_features(features);
     let target_filter = Some(TargetFilter {
         name: "verify-kzg-program".to_string(),
         kind: "bin".to_string(),
@@ -82,13 +157,5 @@ pub fn run_guest_program(input: KzgInputs) {
     pkg_dir.push("tests");
     pkg_dir.push("programs");
     pkg_dir.push("verify_kzg");
-
-    let elf = sdk
-        .build(guest_opts, &pkg_dir, &target_filter, None)
-        .unwrap();
-
-    let mut io = StdIn::default();
-    io.write(&input);
-
-    sdk.app_prover(elf).unwrap().prove(io).unwrap();
+    (guest_opts, pkg_dir, target_filter)
 }
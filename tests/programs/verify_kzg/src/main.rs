@@ -4,7 +4,13 @@
 extern crate alloc;
 
 use openvm::io::read;
-use openvm_kzg::{get_kzg_settings, KzgInputs, KzgProof};
+#[cfg(feature = "self-check")]
+use openvm_kzg::differential;
+#[cfg(not(feature = "self-check"))]
+use openvm_kzg::KzgProof;
+#[cfg(feature = "self-check")]
+use openvm_kzg::VerificationOutcome;
+use openvm_kzg::{get_kzg_point_verifier_settings, KzgInputs};
 #[allow(unused_imports)] // init! macro needs it
 use openvm_pairing::bls12_381::Bls12_381G1Affine;
 
@@ -14,18 +20,41 @@ openvm::init!();
 openvm::entry!(main);
 
 pub fn main() {
-    // Get const trusted setup from disk
-    let kzg_settings = get_kzg_settings();
+    // Only [tau]_2 of the trusted setup is needed to verify a point proof
+    let kzg_settings = get_kzg_point_verifier_settings();
 
     let io: KzgInputs = read();
 
-    let success = KzgProof::verify_kzg_proof(
-        &io.commitment_bytes,
-        &io.z_bytes,
-        &io.y_bytes,
-        &io.proof_bytes,
-        &kzg_settings,
-    )
-    .unwrap();
-    assert!(success);
+    #[cfg(not(feature = "self-check"))]
+    {
+        let outcome = KzgProof::verify_kzg_proof(
+            &io.commitment_bytes,
+            &io.z_bytes,
+            &io.y_bytes,
+            &io.proof_bytes,
+            &kzg_settings,
+        )
+        .unwrap();
+        assert!(outcome.is_valid(), "{outcome}");
+    }
+
+    // Panics if the intrinsic and native backends disagree, and otherwise reveals their verdict
+    // in the terms of the spec tests: 1 if the proof is valid, 0 if not, and 2 if the inputs are
+    // rejected.
+    #[cfg(feature = "self-check")]
+    {
+        let outcome = differential::verify_kzg_proof(
+            &io.commitment_bytes,
+            &io.z_bytes,
+            &io.y_bytes,
+            &io.proof_bytes,
+            &kzg_settings,
+        )
+        .expect_agreement();
+        let verdict = match VerificationOutcome::spec_output(&outcome) {
+            Some(valid) => valid as u32,
+            None => 2,
+        };
+        openvm::io::reveal_u32(verdict, 0);
+    }
 }